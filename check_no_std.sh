@@ -0,0 +1,47 @@
+#!/bin/bash
+# check_no_std.sh
+# Build-only check that the geometry and physics math compiles without std.
+#
+# The .rs sources ship without a manifest, so a throwaway workspace pointing
+# at them is generated for each run and removed afterwards.
+#
+# Usage: ./check_no_std.sh [target]    (default: thumbv7em-none-eabihf)
+
+set -euo pipefail
+
+ROOT="$(cd "$(dirname "$0")" && pwd)"
+TARGET="${1:-thumbv7em-none-eabihf}"
+WORK="$(mktemp -d)"
+trap 'rm -rf "$WORK"' EXIT
+
+cat > "$WORK/Cargo.toml" <<TOML
+[workspace]
+members = ["geometry_core", "physics_core"]
+resolver = "2"
+TOML
+
+for crate in geometry_core physics_core; do
+    mkdir -p "$WORK/$crate"
+    cat > "$WORK/$crate/Cargo.toml" <<TOML
+[package]
+name = "$crate"
+version = "0.0.0"
+edition = "2021"
+
+[lib]
+path = "$ROOT/$crate.rs"
+
+[features]
+default = ["std"]
+std = []
+serde = []
+unicode = []
+
+[dependencies]
+libm = "0.2"
+TOML
+done
+
+echo "Building geometry_core and physics_core without std for $TARGET"
+cargo build --manifest-path "$WORK/Cargo.toml" --workspace --no-default-features \
+    --target "$TARGET"
@@ -7,8 +7,43 @@
 //!
 //! This implements the Enneper Minimal Surface model for
 //! validating logical coherence through geometric relaxation.
+//!
+//! The math core builds without `std` (only `alloc` is required):
+//!
+//! ```text
+//! cargo build --no-default-features --target thumbv7em-none-eabihf
+//! ```
+//!
+//! The `std` feature (on by default) uses the platform float routines and a
+//! `HashSet` for word deduplication; without it, `libm` and a sorted `Vec`
+//! stand in. `check_no_std.sh` (run in CI) builds it that way.
+
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, sync::Arc, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+use core::f64::consts::PI;
+use core::fmt::Write;
 
-use std::f64::consts::PI;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "unicode")]
+use unicode_segmentation::UnicodeSegmentation;
+
+#[path = "tent_hash.rs"]
+mod tent_hash;
+
+pub use tent_hash::{
+    word_hash, DefaultTentHasher, Fnv1aHasher, Poly31Hasher, TentHasher, ANCHOR_WORDS,
+};
 
 // =============================================================================
 // CONSTANTS: The Sacred Ratios
@@ -21,17 +56,144 @@ pub const PHI: f64 = 1.618033988749895;
 pub const DELTA: f64 = 2.414213562373095;
 
 /// Minimal surface tension threshold (below = truth)
+/// Units: average per-word tension, i.e. grid Laplacian magnitude plus word
+/// complexity (length / 10 + 0.1 per symbol) beyond `PLAIN_WORD_TENSION`;
+/// dimensionless
 pub const TENSION_THRESHOLD: f64 = 0.1;
 
+/// Complexity of a plain four-letter word; words only add narrative
+/// tension beyond this, so short factual statements stay near zero
+/// Raw complexity is at least 0.1 per word, which would put every narrative
+/// at or above `TENSION_THRESHOLD` (never Crystal) and plain statements
+/// such as "The sky is blue" (0.3) at the Hallucination boundary
+pub const PLAIN_WORD_TENSION: f64 = 0.4;
+
 /// Mean curvature threshold for minimal surfaces
+/// Units: mean grid Laplacian magnitude over the interior, in surface
+/// coordinates; it scales with resolution and range, see `calibrate`
 pub const CURVATURE_THRESHOLD: f64 = 0.05;
 
+/// Smallest grid with an interior point; lower resolutions are clamped up
+pub const MIN_RESOLUTION: usize = 3;
+
+/// (TENSION_THRESHOLD, CURVATURE_THRESHOLD), before any calibration
+const DEFAULT_THRESHOLDS: (f64, f64) = (TENSION_THRESHOLD, CURVATURE_THRESHOLD);
+
+/// Curvature and tension are rounded to this step before fingerprinting
+const FINGERPRINT_QUANTUM: f64 = 1e-9;
+
+// =============================================================================
+// FLOAT MATH: libm fallback for no_std
+// =============================================================================
+
+/// Transcendental float methods backed by `libm` when `std` is unavailable.
+/// With `std`, the inherent `f64` methods take precedence over this trait.
+#[cfg(not(feature = "std"))]
+#[allow(dead_code)]
+trait FloatMath {
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn cosh(self) -> Self;
+    fn tanh(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+}
+
+#[cfg(not(feature = "std"))]
+impl FloatMath for f64 {
+    fn sqrt(self) -> f64 { libm::sqrt(self) }
+    fn sin(self) -> f64 { libm::sin(self) }
+    fn cos(self) -> f64 { libm::cos(self) }
+    fn cosh(self) -> f64 { libm::cosh(self) }
+    fn tanh(self) -> f64 { libm::tanh(self) }
+    fn powi(self, n: i32) -> f64 { libm::pow(self, n as f64) }
+    fn floor(self) -> f64 { libm::floor(self) }
+    fn ceil(self) -> f64 { libm::ceil(self) }
+    fn round(self) -> f64 { libm::round(self) }
+}
+
+// =============================================================================
+// WORD HASHING
+// =============================================================================
+
+/// Hash a word onto the surface using FNV-1a (`Fnv1aHasher`) over all of
+/// its bytes (over its chars with the `unicode` feature).
+/// Placement keeps FNV-1a rather than `DefaultTentHasher`: the base-31 hash
+/// barely separates words sharing a prefix, which piles them onto the same
+/// cells. The seed is folded into the offset basis so validators can be
+/// decorrelated
+pub fn narrative_hash(word: &str, seed: u64) -> u64 {
+    #[cfg(not(feature = "unicode"))]
+    let hash = Fnv1aHasher.hash_word(word, seed);
+
+    #[cfg(feature = "unicode")]
+    let hash = Fnv1aHasher.hash_chars(word, seed);
+
+    hash
+}
+
+/// Canonical form of a narrative, so trivial formatting cannot change a
+/// verdict. Exactly these transforms are applied, in order:
+/// 1. split on Unicode whitespace (dropping leading/trailing whitespace)
+/// 2. strip leading and trailing non-alphanumeric characters from each word
+///    ("\"Truth.\"" → "Truth"; inner marks as in "don't" or "e=mc" stay)
+/// 3. drop words left empty (pure punctuation such as "—")
+/// 4. lowercase each word (Unicode-aware)
+/// 5. join with single spaces
+///
+/// Applying it twice gives the same result as once.
+pub fn normalize_narrative(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    for word in text.split_whitespace() {
+        let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+        if word.is_empty() {
+            continue;
+        }
+        if !normalized.is_empty() {
+            normalized.push(' ');
+        }
+        normalized.push_str(&word.to_lowercase());
+    }
+    normalized
+}
+
+/// Length of a word or text: bytes by default, chars with the `unicode` feature
+fn text_len(text: &str) -> usize {
+    #[cfg(feature = "unicode")]
+    {
+        text.chars().count()
+    }
+    #[cfg(not(feature = "unicode"))]
+    {
+        text.len()
+    }
+}
+
+/// Number of distinct words, deduplicated with a `HashSet` under `std`.
+#[cfg(feature = "std")]
+fn unique_word_count(words: &[&str]) -> usize {
+    words.iter().collect::<std::collections::HashSet<_>>().len()
+}
+
+/// Number of distinct words, deduplicated with a sorted `Vec` under `no_std`.
+#[cfg(not(feature = "std"))]
+fn unique_word_count(words: &[&str]) -> usize {
+    let mut sorted = words.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    sorted.len()
+}
+
 // =============================================================================
 // CORE DATA STRUCTURES
 // =============================================================================
 
 /// A point in 3D semantic space
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Point3D {
     pub x: f64,
     pub y: f64,
@@ -47,14 +209,21 @@ impl Point3D {
         (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
     }
 
+    /// Unit vector in the same direction; zero, NaN and infinite vectors
+    /// normalize to the zero vector
     pub fn normalize(&self) -> Self {
         let m = self.magnitude();
-        if m == 0.0 {
-            return *self;
+        if m == 0.0 || !m.is_finite() {
+            return Self::new(0.0, 0.0, 0.0);
         }
         Self::new(self.x / m, self.y / m, self.z / m)
     }
 
+    /// Whether every coordinate is finite (no NaN or infinity)
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
     pub fn cross(&self, other: &Point3D) -> Self {
         Self::new(
             self.y * other.z - self.z * other.y,
@@ -69,14 +238,35 @@ impl Point3D {
 }
 
 /// Result of truth validation
-#[derive(Debug)]
+///
+/// With the `serde` feature this serializes internally tagged, e.g.
+/// `{"state":"Crystal","curvature":0.01,"tension":0.05}`. The unbounded
+/// tension of degenerate input is written as `"Infinity"`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "state"))]
 pub enum TruthState {
     /// Zero mean curvature - stable truth
-    Crystal { curvature: f64, tension: f64 },
+    Crystal {
+        #[cfg_attr(feature = "serde", serde(with = "serde_float"))]
+        curvature: f64,
+        #[cfg_attr(feature = "serde", serde(with = "serde_float"))]
+        tension: f64,
+    },
     /// Non-zero but bounded - uncertain
-    Annealing { curvature: f64, tension: f64 },
+    Annealing {
+        #[cfg_attr(feature = "serde", serde(with = "serde_float"))]
+        curvature: f64,
+        #[cfg_attr(feature = "serde", serde(with = "serde_float"))]
+        tension: f64,
+    },
     /// High tension - falsehood
-    Hallucination { curvature: f64, tension: f64 },
+    Hallucination {
+        #[cfg_attr(feature = "serde", serde(with = "serde_float"))]
+        curvature: f64,
+        #[cfg_attr(feature = "serde", serde(with = "serde_float"))]
+        tension: f64,
+    },
 }
 
 impl TruthState {
@@ -91,6 +281,51 @@ impl TruthState {
             TruthState::Hallucination { tension, .. } => *tension,
         }
     }
+
+    pub fn curvature(&self) -> f64 {
+        match self {
+            TruthState::Crystal { curvature, .. } => *curvature,
+            TruthState::Annealing { curvature, .. } => *curvature,
+            TruthState::Hallucination { curvature, .. } => *curvature,
+        }
+    }
+
+    /// Confidence in the narrative's truth, 0–100, against the default
+    /// thresholds; see `confidence_with`
+    ///
+    /// After `calibrate` or a threshold override, use
+    /// `NarrativeGeometry::confidence` so the score agrees with the verdict.
+    pub fn confidence(&self) -> f64 {
+        self.confidence_with(DEFAULT_THRESHOLDS)
+    }
+
+    /// Confidence in the narrative's truth, 0–100, against the given
+    /// (tension, curvature) thresholds
+    ///
+    /// Uses the stress s = max(tension / tension threshold,
+    /// min(curvature / curvature threshold, 1)), which is below 1 exactly for
+    /// Crystal and at least 3 exactly for Hallucination, and maps it through
+    /// 100 / (1 + s²). The score is continuous across the state boundaries:
+    /// Crystal scores above 50, Annealing in (10, 50], Hallucination 10 or
+    /// less. NaN scores 0.
+    pub fn confidence_with(&self, thresholds: (f64, f64)) -> f64 {
+        if self.tension().is_nan() {
+            return 0.0;
+        }
+        let (tension_threshold, curvature_threshold) = thresholds;
+        let stress = (self.tension() / tension_threshold)
+            .max((self.curvature() / curvature_threshold).min(1.0));
+        100.0 / (1.0 + stress * stress)
+    }
+
+    /// Variant name
+    pub fn name(&self) -> &'static str {
+        match self {
+            TruthState::Crystal { .. } => "Crystal",
+            TruthState::Annealing { .. } => "Annealing",
+            TruthState::Hallucination { .. } => "Hallucination",
+        }
+    }
 }
 
 // =============================================================================
@@ -99,42 +334,113 @@ impl TruthState {
 
 /// The Enneper Surface - a minimal surface that allows self-intersection
 /// without breaking. This models how valid paradoxes can exist.
+#[derive(Clone)]
 pub struct EnneperSurface {
-    /// Resolution of the parametric grid
+    /// Resolution of the parametric grid (always ≥ MIN_RESOLUTION)
     pub resolution: usize,
+    /// Half-width of the parametric window: u, v ∈ [-range, range)
+    pub range: f64,
     /// Generated surface points
     pub points: Vec<Vec<Point3D>>,
     /// Surface normals
     pub normals: Vec<Vec<Point3D>>,
+    /// Discrete tension per grid point, row-major (zero on the border)
+    curvature_field: Vec<f64>,
+    /// Interior cells of each interior row, flattest |K| first, row by row
+    flat_cells: Vec<usize>,
 }
 
 impl EnneperSurface {
     /// Create a new Enneper surface with given resolution
     pub fn new(resolution: usize) -> Self {
+        Self::with_range(resolution, 2.0)
+    }
+
+    /// Create an Enneper surface over the parametric window [-range, range)
+    /// Resolutions below MIN_RESOLUTION are clamped up so every consumer
+    /// has at least one interior grid point
+    pub fn with_range(resolution: usize, range: f64) -> Self {
+        let resolution = resolution.max(MIN_RESOLUTION);
         let mut surface = Self {
             resolution,
+            range,
             points: Vec::with_capacity(resolution),
             normals: Vec::with_capacity(resolution),
+            curvature_field: Vec::new(),
+            flat_cells: Vec::new(),
         };
         surface.generate();
         surface
     }
 
+    /// Rebuild points, normals and the cached curvature field from the
+    /// current resolution and range
+    pub fn regenerate(&mut self) {
+        self.resolution = self.resolution.max(MIN_RESOLUTION);
+        self.points.clear();
+        self.normals.clear();
+        self.generate();
+    }
+
+    /// Recompute the cached curvature field and flat-cell ranking after
+    /// editing `points` directly
+    pub fn refresh_curvature_field(&mut self) {
+        let res = self.resolution;
+        self.curvature_field = (0..res * res)
+            .map(|k| self.laplacian_magnitude(k / res, k % res))
+            .collect();
+
+        self.flat_cells = (1..res - 1)
+            .flat_map(|i| {
+                let mut cells: Vec<(f64, usize)> =
+                    (1..res - 1).map(|j| (self.gaussian_curvature(i, j).abs(), j)).collect();
+                cells.sort_by(|a, b| a.0.total_cmp(&b.0));
+                cells.into_iter().map(|(_, j)| j)
+            })
+            .collect();
+    }
+
+    /// Interior cells (v indices) of row `i`, ordered by |Gaussian curvature|,
+    /// flattest first; `i` is clamped to the interior rows
+    pub fn flattest_cells(&self, i: usize) -> &[usize] {
+        let interior = self.resolution - 2;
+        let row = i.clamp(1, interior) - 1;
+        &self.flat_cells[row * interior..(row + 1) * interior]
+    }
+
+    /// Cached discrete tension per grid point, row-major: index i * resolution + j
+    pub fn curvature_field(&self) -> &[f64] {
+        &self.curvature_field
+    }
+
+    /// Surface point at grid indices (i, j), or `None` outside the grid
+    pub fn point_at(&self, i: usize, j: usize) -> Option<&Point3D> {
+        self.points.get(i)?.get(j)
+    }
+
+    /// Unit normal at grid indices (i, j), or `None` outside the grid
+    pub fn normal_at(&self, i: usize, j: usize) -> Option<&Point3D> {
+        self.normals.get(i)?.get(j)
+    }
+
+    /// Parameter value (u or v) for a grid index
+    pub fn parameter(&self, index: usize) -> f64 {
+        let step = 2.0 * self.range / (self.resolution as f64);
+        -self.range + (index as f64) * step
+    }
+
     /// Generate the Enneper surface using parametric equations:
     /// x(u,v) = u - u³/3 + uv²
     /// y(u,v) = v - v³/3 + u²v
     /// z(u,v) = u² - v²
     fn generate(&mut self) {
-        let range = 2.0;
-        let step = 2.0 * range / (self.resolution as f64);
-
         for i in 0..self.resolution {
-            let u = -range + (i as f64) * step;
+            let u = self.parameter(i);
             let mut row_points = Vec::with_capacity(self.resolution);
             let mut row_normals = Vec::with_capacity(self.resolution);
 
             for j in 0..self.resolution {
-                let v = -range + (j as f64) * step;
+                let v = self.parameter(j);
 
                 // Enneper parametric equations
                 let x = u - u.powi(3) / 3.0 + u * v.powi(2);
@@ -153,11 +459,68 @@ impl EnneperSurface {
             self.points.push(row_points);
             self.normals.push(row_normals);
         }
+
+        self.refresh_curvature_field();
+    }
+
+    /// Analytic mean curvature at parameters (u, v)
+    /// H = (E·N - 2F·M + G·L) / (2(EG - F²)), which is 0 for a minimal surface
+    pub fn mean_curvature_analytic(&self, u: f64, v: f64) -> f64 {
+        // First derivatives
+        let xu = Point3D::new(1.0 - u.powi(2) + v.powi(2), 2.0 * u * v, 2.0 * u);
+        let xv = Point3D::new(2.0 * u * v, 1.0 - v.powi(2) + u.powi(2), -2.0 * v);
+
+        // Second derivatives
+        let xuu = Point3D::new(-2.0 * u, 2.0 * v, 2.0);
+        let xuv = Point3D::new(2.0 * v, 2.0 * u, 0.0);
+        let xvv = Point3D::new(2.0 * u, -2.0 * v, -2.0);
+
+        let normal = xu.cross(&xv).normalize();
+
+        // First fundamental form
+        let e = xu.dot(&xu);
+        let f = xu.dot(&xv);
+        let g = xv.dot(&xv);
+
+        // Second fundamental form
+        let l = xuu.dot(&normal);
+        let m = xuv.dot(&normal);
+        let n = xvv.dot(&normal);
+
+        let denom = 2.0 * (e * g - f * f);
+        if !denom.is_finite() || denom.abs() < f64::EPSILON {
+            return 0.0;
+        }
+
+        let h = (e * n - 2.0 * f * m + g * l) / denom;
+        if h.is_finite() {
+            h
+        } else {
+            0.0
+        }
+    }
+
+    /// Discrete tension at a grid point: magnitude of the five-point Laplacian
+    /// This depends on grid spacing and is not the true mean curvature.
+    /// Read from the cached curvature field.
+    pub fn discrete_tension(&self, i: usize, j: usize) -> f64 {
+        let last = self.resolution.saturating_sub(1);
+        if i == 0 || i >= last || j == 0 || j >= last {
+            return 0.0;
+        }
+        self.curvature_field[i * self.resolution + j]
     }
 
-    /// Compute mean curvature at a point (H = 0 for minimal surface)
+    /// Former name of `discrete_tension`, kept for existing callers
+    #[deprecated(note = "use `discrete_tension`; this was never the true mean curvature")]
     pub fn mean_curvature(&self, i: usize, j: usize) -> f64 {
-        if i == 0 || i >= self.resolution - 1 || j == 0 || j >= self.resolution - 1 {
+        self.discrete_tension(i, j)
+    }
+
+    /// Five-point Laplacian magnitude computed from the points on demand
+    fn laplacian_magnitude(&self, i: usize, j: usize) -> f64 {
+        let last = self.resolution.saturating_sub(1);
+        if i == 0 || i >= last || j == 0 || j >= last {
             return 0.0;
         }
 
@@ -170,7 +533,7 @@ impl EnneperSurface {
         let up = &p[i][j - 1];
         let down = &p[i][j + 1];
 
-        // Laplacian approximation for mean curvature
+        // Laplacian approximation of the curvature vector
         let laplacian = Point3D::new(
             left.x + right.x + up.x + down.x - 4.0 * center.x,
             left.y + right.y + up.y + down.y - 4.0 * center.y,
@@ -180,48 +543,341 @@ impl EnneperSurface {
         laplacian.magnitude()
     }
 
-    /// Compute total surface tension (sum of mean curvatures)
-    pub fn total_tension(&self) -> f64 {
-        let mut total = 0.0;
-        let mut count = 0;
+    /// Geometric relaxation: move every interior point along its discrete
+    /// mean-curvature vector, p += rate/4 · Δp (Laplacian smoothing), with
+    /// the border held fixed. `rate` is clamped to [0, 1], where each
+    /// iteration cannot increase `total_tension`. Interior normals are
+    /// rebuilt from central differences and the curvature field refreshed.
+    pub fn relax(&mut self, iterations: usize, rate: f64) {
+        let rate = rate.clamp(0.0, 1.0);
+        if rate.is_nan() || rate == 0.0 {
+            return;
+        }
+
+        let last = self.resolution - 1;
+        let step = rate / 4.0;
+        for _ in 0..iterations {
+            // Jacobi update: every point moves against the previous iteration
+            let p = &self.points;
+            let mut next = p.clone();
+            for i in 1..last {
+                for j in 1..last {
+                    let c = p[i][j];
+                    let (l, r, u, d) = (p[i - 1][j], p[i + 1][j], p[i][j - 1], p[i][j + 1]);
+                    next[i][j] = Point3D::new(
+                        c.x + step * (l.x + r.x + u.x + d.x - 4.0 * c.x),
+                        c.y + step * (l.y + r.y + u.y + d.y - 4.0 * c.y),
+                        c.z + step * (l.z + r.z + u.z + d.z - 4.0 * c.z),
+                    );
+                }
+            }
+            self.points = next;
+        }
 
-        for i in 1..self.resolution - 1 {
-            for j in 1..self.resolution - 1 {
-                total += self.mean_curvature(i, j);
-                count += 1;
+        let p = &self.points;
+        for i in 1..last {
+            for j in 1..last {
+                let (l, r, u, d) = (p[i - 1][j], p[i + 1][j], p[i][j - 1], p[i][j + 1]);
+                let xu = Point3D::new(r.x - l.x, r.y - l.y, r.z - l.z);
+                let xv = Point3D::new(d.x - u.x, d.y - u.y, d.z - u.z);
+                self.normals[i][j] = xu.cross(&xv).normalize();
             }
         }
+        self.refresh_curvature_field();
+    }
+
+    /// Gaussian curvature at a grid point from the discrete shape operator:
+    /// K = (LN - M²) / (EG - F²), with central differences of `points` for
+    /// the tangent and second derivatives and the stored `normals`
+    /// Enneper's surface is a saddle everywhere, so interior values are
+    /// negative; border points and degenerate grids report 0.
+    pub fn gaussian_curvature(&self, i: usize, j: usize) -> f64 {
+        let last = self.resolution.saturating_sub(1);
+        if i == 0 || i >= last || j == 0 || j >= last {
+            return 0.0;
+        }
+
+        let p = &self.points;
+        let h = 2.0 * self.range / self.resolution as f64;
+
+        // Weighted sum of grid points scaled by 1/divisor
+        let combine = |terms: &[(f64, &Point3D)], divisor: f64| {
+            let sum = terms.iter().fold(Point3D::new(0.0, 0.0, 0.0), |acc, (w, q)| {
+                Point3D::new(acc.x + w * q.x, acc.y + w * q.y, acc.z + w * q.z)
+            });
+            Point3D::new(sum.x / divisor, sum.y / divisor, sum.z / divisor)
+        };
+
+        let xu = combine(&[(1.0, &p[i + 1][j]), (-1.0, &p[i - 1][j])], 2.0 * h);
+        let xv = combine(&[(1.0, &p[i][j + 1]), (-1.0, &p[i][j - 1])], 2.0 * h);
+        let xuu = combine(&[(1.0, &p[i + 1][j]), (-2.0, &p[i][j]), (1.0, &p[i - 1][j])], h * h);
+        let xvv = combine(&[(1.0, &p[i][j + 1]), (-2.0, &p[i][j]), (1.0, &p[i][j - 1])], h * h);
+        let xuv = combine(
+            &[
+                (1.0, &p[i + 1][j + 1]),
+                (-1.0, &p[i + 1][j - 1]),
+                (-1.0, &p[i - 1][j + 1]),
+                (1.0, &p[i - 1][j - 1]),
+            ],
+            4.0 * h * h,
+        );
+
+        let normal = &self.normals[i][j];
+
+        // First and second fundamental forms
+        let (e, f, g) = (xu.dot(&xu), xu.dot(&xv), xv.dot(&xv));
+        let (l, m, n) = (xuu.dot(normal), xuv.dot(normal), xvv.dot(normal));
+
+        let first = e * g - f * f;
+        if !first.is_finite() || first.abs() < f64::EPSILON {
+            return 0.0;
+        }
 
-        if count > 0 {
-            total / count as f64
+        let k = (l * n - m * m) / first;
+        if k.is_finite() {
+            k
         } else {
             0.0
         }
     }
+
+    /// Compute total surface tension (mean of discrete tensions)
+    pub fn total_tension(&self) -> f64 {
+        let interior = self.resolution.saturating_sub(2);
+        let count = interior * interior;
+        if count == 0 {
+            return 0.0;
+        }
+
+        // Border cells are zero in the field, so summing all of it is exact
+        let total: f64 = self.curvature_field.iter().sum();
+        total / count as f64
+    }
+
+    /// Export the surface as Wavefront OBJ (vertices, normals, quad faces)
+    /// Vertex k = i * resolution + j + 1 (OBJ indices are 1-based)
+    pub fn to_obj(&self) -> String {
+        let mut obj = String::new();
+
+        for row in &self.points {
+            for p in row {
+                let _ = writeln!(obj, "v {} {} {}", p.x, p.y, p.z);
+            }
+        }
+
+        for row in &self.normals {
+            for n in row {
+                let _ = writeln!(obj, "vn {} {} {}", n.x, n.y, n.z);
+            }
+        }
+
+        let res = self.resolution;
+        for i in 0..res.saturating_sub(1) {
+            for j in 0..res - 1 {
+                let a = i * res + j + 1;
+                let b = (i + 1) * res + j + 1;
+                let c = (i + 1) * res + j + 2;
+                let d = i * res + j + 2;
+                let _ = writeln!(obj, "f {0}//{0} {1}//{1} {2}//{2} {3}//{3}", a, b, c, d);
+            }
+        }
+
+        obj
+    }
 }
 
 // =============================================================================
 // NARRATIVE GEOMETRY MAPPER
 // =============================================================================
 
+/// How `map_narrative` scales each word's tension by its position
+/// Weights lie in [0.5, 1.5] and average about 1 over a narrative, so the
+/// tension thresholds keep their meaning.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TensionWeighting {
+    /// Every word counts the same
+    #[default]
+    Uniform,
+    /// The opening (subject) weighs 1.5, falling to 0.5 at the end
+    Primacy,
+    /// The final assertion weighs 1.5, rising from 0.5 at the start
+    Recency,
+    /// Both ends weigh 1.5, the middle 0.5
+    Edges,
+}
+
+impl TensionWeighting {
+    /// Weight of the word at `idx` of `word_count`
+    pub fn weight(&self, idx: usize, word_count: usize) -> f64 {
+        if word_count < 2 {
+            return 1.0;
+        }
+        // Position along the narrative in [0, 1]
+        let x = idx as f64 / (word_count - 1) as f64;
+        match self {
+            TensionWeighting::Uniform => 1.0,
+            TensionWeighting::Primacy => 1.5 - x,
+            TensionWeighting::Recency => 0.5 + x,
+            TensionWeighting::Edges => 0.5 + 2.0 * (x - 0.5).abs(),
+        }
+    }
+}
+
+/// How `map_narrative` picks the v cell of each word (u follows word order)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PlacementMode {
+    /// v = word hash mod resolution
+    #[default]
+    Modulo,
+    /// Rank the interior cells of the word's row by |Gaussian curvature|
+    /// (precomputed per surface, see `EnneperSurface::flattest_cells`) and
+    /// pick by the word's own tension t, at fraction t / (1 + t) of the
+    /// ranking: plain words settle in the flattest cells, high-tension words
+    /// drift toward the saddles
+    CurvatureWeighted,
+}
+
 /// Maps a text narrative onto a geometric surface for validation
+///
+/// The surface is only read while mapping, so it is held behind an `Arc` and
+/// can be shared by many mappers (and threads); each mapper owns just its
+/// tension field.
+#[derive(Clone)]
 pub struct NarrativeGeometry {
-    /// The underlying Enneper surface
-    surface: EnneperSurface,
+    /// The underlying Enneper surface, never mutated during mapping
+    surface: Arc<EnneperSurface>,
     /// Mapped tension field from text
     tension_field: Vec<f64>,
+    /// Positional scaling of word tension
+    weighting: TensionWeighting,
+    /// (tension, curvature) thresholds used to classify, see `calibrate`
+    thresholds: (f64, f64),
+    /// How words pick their v cell
+    placement: PlacementMode,
 }
 
 impl NarrativeGeometry {
     pub fn new(resolution: usize) -> Self {
+        Self::with_range(resolution, 2.0)
+    }
+
+    /// Map narratives onto an Enneper surface over [-range, range)
+    pub fn with_range(resolution: usize, range: f64) -> Self {
+        Self::with_surface(Arc::new(EnneperSurface::with_range(resolution, range)))
+    }
+
+    /// Map narratives onto an existing, shared surface
+    pub fn with_surface(surface: Arc<EnneperSurface>) -> Self {
         Self {
-            surface: EnneperSurface::new(resolution),
+            surface,
             tension_field: Vec::new(),
+            weighting: TensionWeighting::Uniform,
+            thresholds: DEFAULT_THRESHOLDS,
+            placement: PlacementMode::Modulo,
+        }
+    }
+
+    /// The shared surface, e.g. to build more mappers with `with_surface`
+    pub fn surface(&self) -> &Arc<EnneperSurface> {
+        &self.surface
+    }
+
+    /// Scale word tension by position in later `map_narrative` calls
+    pub fn set_weighting(&mut self, weighting: TensionWeighting) {
+        self.weighting = weighting;
+    }
+
+    pub fn weighting(&self) -> TensionWeighting {
+        self.weighting
+    }
+
+    /// Choose how later `map_narrative` and `narrative_path` calls place words
+    pub fn set_placement(&mut self, placement: PlacementMode) {
+        self.placement = placement;
+    }
+
+    pub fn placement(&self) -> PlacementMode {
+        self.placement
+    }
+
+    /// (tension, curvature) thresholds: the constants until `calibrate`
+    pub fn thresholds(&self) -> (f64, f64) {
+        self.thresholds
+    }
+
+    /// Override the (tension, curvature) thresholds, e.g. with values a
+    /// previous `calibrate` returned
+    pub fn set_thresholds(&mut self, thresholds: (f64, f64)) {
+        self.thresholds = thresholds;
+    }
+
+    /// Confidence in `state`, 0–100, against this mapper's thresholds
+    pub fn confidence(&self, state: &TruthState) -> f64 {
+        state.confidence_with(self.thresholds)
+    }
+
+    /// Fit the classification thresholds to labeled narratives
+    ///
+    /// A narrative counts as accepted when `map_narrative` calls it Crystal
+    /// (average tension and curvature both below their thresholds). Every
+    /// pair of candidate thresholds (the current values, the midpoints
+    /// between observed values, and values below/above all of them) is tried
+    /// and the pair maximizing Youden's J = TPR - FPR is kept, where TPR is
+    /// the share of `known_truths` accepted and FPR the share of `known_lies`
+    /// accepted. Ties keep the earlier candidate, so the current thresholds
+    /// survive unless beaten. The Annealing band follows as 3× the tension
+    /// threshold. With either set empty nothing changes.
+    ///
+    /// Returns the (tension, curvature) thresholds now in use.
+    pub fn calibrate(&mut self, known_truths: &[&str], known_lies: &[&str]) -> (f64, f64) {
+        if known_truths.is_empty() || known_lies.is_empty() {
+            return self.thresholds;
+        }
+
+        let mut measure = |texts: &[&str]| -> Vec<(f64, f64)> {
+            texts
+                .iter()
+                .map(|text| {
+                    let state = self.map_narrative(text);
+                    (state.tension(), state.curvature())
+                })
+                .collect()
+        };
+        let truths = measure(known_truths);
+        let lies = measure(known_lies);
+
+        let all = || truths.iter().chain(&lies);
+        let tension_candidates =
+            threshold_candidates(all().map(|s| s.0).collect(), self.thresholds.0);
+        let curvature_candidates =
+            threshold_candidates(all().map(|s| s.1).collect(), self.thresholds.1);
+
+        let accepted_share = |samples: &[(f64, f64)], t: f64, c: f64| {
+            samples.iter().filter(|s| s.0 < t && s.1 < c).count() as f64 / samples.len() as f64
+        };
+
+        let mut best = (f64::NEG_INFINITY, self.thresholds);
+        for &t in &tension_candidates {
+            for &c in &curvature_candidates {
+                let j = accepted_share(&truths, t, c) - accepted_share(&lies, t, c);
+                if j > best.0 {
+                    best = (j, (t, c));
+                }
+            }
         }
+
+        self.thresholds = best.1;
+        self.thresholds
     }
 
     /// Map a narrative (text) onto the surface
-    /// Each word contributes to local curvature
+    /// Each word contributes to local curvature. Word k sits on interior row
+    /// k mod (resolution - 2) + 1, the same cell `NarrativeStream` uses;
+    /// before streaming shared the layout, rows were spread in proportion to
+    /// k / word count, so verdicts on surfaces with nonzero local tension
+    /// differ from earlier releases.
     pub fn map_narrative(&mut self, text: &str) -> TruthState {
         let words: Vec<&str> = text.split_whitespace().collect();
         let word_count = words.len();
@@ -238,24 +894,18 @@ impl NarrativeGeometry {
         let mut total_tension = 0.0;
 
         for (idx, word) in words.iter().enumerate() {
-            let hash = self.word_hash(word);
-
-            // Map hash to surface coordinates
-            let u = (idx as f64 / word_count as f64) * (self.surface.resolution - 1) as f64;
-            let v = (hash % self.surface.resolution as u64) as f64;
-
-            let i = u as usize;
-            let j = v as usize;
+            let (i, j) = self.word_cell(idx, word);
 
             // Get local curvature at this word's position
-            let local_curvature = self.surface.mean_curvature(
+            let local_curvature = self.surface.discrete_tension(
                 i.min(self.surface.resolution - 2).max(1),
                 j.min(self.surface.resolution - 2).max(1),
             );
 
             // Add word-specific tension (based on character complexity)
-            let word_tension = self.word_tension(word);
-            let combined = local_curvature + word_tension;
+            let word_tension = Self::narrative_tension(word);
+            let weight = self.weighting.weight(idx, word_count);
+            let combined = (local_curvature + word_tension) * weight;
 
             self.tension_field.push(combined);
             total_tension += combined;
@@ -264,46 +914,210 @@ impl NarrativeGeometry {
         let avg_tension = total_tension / word_count as f64;
         let avg_curvature = self.surface.total_tension();
 
-        // Classify based on tension/curvature
-        if avg_tension < TENSION_THRESHOLD && avg_curvature < CURVATURE_THRESHOLD {
-            TruthState::Crystal {
-                curvature: avg_curvature,
-                tension: avg_tension,
-            }
-        } else if avg_tension < TENSION_THRESHOLD * 3.0 {
-            TruthState::Annealing {
-                curvature: avg_curvature,
-                tension: avg_tension,
-            }
-        } else {
-            TruthState::Hallucination {
-                curvature: avg_curvature,
-                tension: avg_tension,
-            }
-        }
+        classify(avg_tension, avg_curvature, self.thresholds)
     }
 
-    /// Compute a hash for a word (prime-based)
-    fn word_hash(&self, word: &str) -> u64 {
-        let primes = [2u64, 3, 5, 7, 11, 13, 17, 19, 23, 29];
-        let mut hash = 0u64;
+    /// Change in average tension from narrative `a` to narrative `b`
+    /// Positive means the edit made the statement less truth-like
+    pub fn tension_delta(&mut self, a: &str, b: &str) -> f64 {
+        let before = self.map_narrative(a).tension();
+        let after = self.map_narrative(b).tension();
+        after - before
+    }
 
-        for (i, c) in word.chars().enumerate() {
-            let prime = primes[i % primes.len()];
-            hash = hash.wrapping_add((c as u64).wrapping_mul(prime));
-        }
+    /// Per-word tension from the last `map_narrative`
+    pub fn tension_field(&self) -> &[f64] {
+        &self.tension_field
+    }
+
+    /// Surface point each word maps to, in word order
+    /// Uses the same cells as `map_narrative`, for rendering the narrative
+    /// as a path in space
+    pub fn narrative_path(&self, text: &str) -> Vec<Point3D> {
+        let words: Vec<&str> = text.split_whitespace().collect();
+        words
+            .iter()
+            .enumerate()
+            .map(|(idx, word)| {
+                let (i, j) = self.word_cell(idx, word);
+                self.surface.points[i][j]
+            })
+            .collect()
+    }
 
-        hash
+    /// Grid cell (u index, v index) for the word at `idx`, see `word_cell`
+    fn word_cell(&self, idx: usize, word: &str) -> (usize, usize) {
+        word_cell(&self.surface, self.placement, idx, word)
+    }
+
+    /// Start an incremental word-by-word validation over this surface,
+    /// using this mapper's thresholds and placement
+    pub fn stream(&self) -> NarrativeStream<'_> {
+        let mut stream = NarrativeStream::new(&self.surface);
+        stream.thresholds = self.thresholds;
+        stream.placement = self.placement;
+        stream
     }
 
     /// Compute tension contribution of a single word
-    fn word_tension(&self, word: &str) -> f64 {
-        let len = word.len() as f64;
-        let complexity = word.chars().filter(|c| !c.is_alphanumeric()).count() as f64;
+    fn word_tension(word: &str) -> f64 {
+        // With `unicode`, count grapheme clusters so "café" weighs the same
+        // whether composed or decomposed
+        #[cfg(feature = "unicode")]
+        let (len, complexity) = {
+            let graphemes: Vec<&str> = word.graphemes(true).collect();
+            let special = graphemes
+                .iter()
+                .filter(|g| !g.chars().next().is_some_and(char::is_alphanumeric))
+                .count();
+            (graphemes.len() as f64, special as f64)
+        };
+        #[cfg(not(feature = "unicode"))]
+        let (len, complexity) = (
+            word.len() as f64,
+            word.chars().filter(|c| !c.is_alphanumeric()).count() as f64,
+        );
 
         // Longer words and special characters add tension
         (len / 10.0) + (complexity * 0.1)
     }
+
+    /// Tension a word adds to a narrative: its complexity beyond a plain word
+    fn narrative_tension(word: &str) -> f64 {
+        (Self::word_tension(word) - PLAIN_WORD_TENSION).max(0.0)
+    }
+}
+
+/// Grid cell (u index, v index) for the word at `idx` of a narrative
+/// u steps through the interior rows in word order, wrapping, so a word's
+/// cell never depends on the words after it and `NarrativeStream` can place
+/// it on arrival; v depends on the placement mode
+fn word_cell(
+    surface: &EnneperSurface,
+    placement: PlacementMode,
+    idx: usize,
+    word: &str,
+) -> (usize, usize) {
+    let res = surface.resolution;
+    let i = (idx % (res - 2)) + 1;
+
+    let j = match placement {
+        PlacementMode::Modulo => (narrative_hash(word, 0) % res as u64) as usize,
+        PlacementMode::CurvatureWeighted => {
+            let cells = surface.flattest_cells(i);
+            let tension = NarrativeGeometry::word_tension(word);
+            let rank = (tension / (1.0 + tension) * cells.len() as f64) as usize;
+            cells[rank.min(cells.len() - 1)]
+        }
+    };
+
+    (i, j)
+}
+
+/// Threshold candidates for `calibrate`: `current` first, then one at the
+/// smallest value (rejects everything), the midpoints between distinct
+/// values, and one above the largest. NaN samples are skipped.
+fn threshold_candidates(mut values: Vec<f64>, current: f64) -> Vec<f64> {
+    values.retain(|v| !v.is_nan());
+    values.sort_by(f64::total_cmp);
+    values.dedup();
+
+    let mut candidates = Vec::with_capacity(values.len() + 2);
+    candidates.push(current);
+    if let (Some(&first), Some(&last)) = (values.first(), values.last()) {
+        candidates.push(first);
+        candidates.extend(values.windows(2).map(|w| (w[0] + w[1]) / 2.0));
+        candidates.push(last + (last - first).max(last.abs()).max(f64::EPSILON));
+    }
+    candidates
+}
+
+/// Classify averaged tension and curvature into a TruthState, given the
+/// (tension, curvature) thresholds
+/// NaN from degenerate input counts as unbounded: Hallucination at infinity
+fn classify(avg_tension: f64, avg_curvature: f64, thresholds: (f64, f64)) -> TruthState {
+    let unbounded = |x: f64| if x.is_nan() { f64::INFINITY } else { x };
+    let (avg_tension, avg_curvature) = (unbounded(avg_tension), unbounded(avg_curvature));
+    let (tension_threshold, curvature_threshold) = thresholds;
+
+    if avg_tension < tension_threshold && avg_curvature < curvature_threshold {
+        TruthState::Crystal {
+            curvature: avg_curvature,
+            tension: avg_tension,
+        }
+    } else if avg_tension < tension_threshold * 3.0 {
+        TruthState::Annealing {
+            curvature: avg_curvature,
+            tension: avg_tension,
+        }
+    } else {
+        TruthState::Hallucination {
+            curvature: avg_curvature,
+            tension: avg_tension,
+        }
+    }
+}
+
+// =============================================================================
+// NARRATIVE STREAM: Incremental Validation
+// =============================================================================
+
+/// Word-by-word narrative validation with a running verdict
+///
+/// Words land on the same cells as in `map_narrative`, so the final verdict
+/// matches the batch one. Tension is always weighted uniformly, as the final
+/// length is unknown while streaming.
+pub struct NarrativeStream<'a> {
+    surface: &'a EnneperSurface,
+    placement: PlacementMode,
+    tension_field: Vec<f64>,
+    total_tension: f64,
+    avg_curvature: f64,
+    thresholds: (f64, f64),
+}
+
+impl<'a> NarrativeStream<'a> {
+    pub fn new(surface: &'a EnneperSurface) -> Self {
+        Self {
+            surface,
+            placement: PlacementMode::default(),
+            tension_field: Vec::new(),
+            total_tension: 0.0,
+            avg_curvature: surface.total_tension(),
+            thresholds: DEFAULT_THRESHOLDS,
+        }
+    }
+
+    /// Add one word, updating the running accumulators in O(1)
+    pub fn push_word(&mut self, word: &str) {
+        let res = self.surface.resolution;
+        let (i, j) = word_cell(self.surface, self.placement, self.tension_field.len(), word);
+        let j = j.clamp(1, res - 2);
+
+        let combined =
+            self.surface.discrete_tension(i, j) + NarrativeGeometry::narrative_tension(word);
+
+        self.tension_field.push(combined);
+        self.total_tension += combined;
+    }
+
+    /// Verdict for the words pushed so far
+    pub fn current_state(&self) -> TruthState {
+        if self.tension_field.is_empty() {
+            return TruthState::Annealing {
+                curvature: 0.0,
+                tension: 0.0,
+            };
+        }
+
+        let avg_tension = self.total_tension / self.tension_field.len() as f64;
+        classify(avg_tension, self.avg_curvature, self.thresholds)
+    }
+
+    /// Per-word tension so far
+    pub fn tension_field(&self) -> &[f64] {
+        &self.tension_field
+    }
 }
 
 // =============================================================================
@@ -311,21 +1125,51 @@ impl NarrativeGeometry {
 // =============================================================================
 
 /// The dual-frequency stabilizer using Golden and Silver ratios
+#[derive(Clone)]
 pub struct PoincareSectionValidator {
     /// Golden winding number
     pub golden_phase: f64,
     /// Silver winding number
     pub silver_phase: f64,
+    /// Distance from a rational `n·π/5` counted as resonance.
+    /// Larger tolerance means stricter lie rejection.
+    pub resonance_tolerance: f64,
+    /// Rationals `n·π/5` are swept for `n` in `1..max_rational_n`
+    pub max_rational_n: u32,
+    /// Unlocked steps seen by `validate_sequence_lenient` since the last reset
+    unlocked_steps: usize,
 }
 
 impl PoincareSectionValidator {
     pub fn new() -> Self {
+        Self::with_tolerance(0.01, 10)
+    }
+
+    /// Create a validator with a custom resonance tolerance and sweep
+    /// A larger `tolerance` rejects more sequences as lies
+    pub fn with_tolerance(tolerance: f64, max_rational_n: u32) -> Self {
         Self {
             golden_phase: 0.0,
             silver_phase: 0.0,
+            resonance_tolerance: tolerance,
+            max_rational_n,
+            unlocked_steps: 0,
         }
     }
 
+    /// Return both spirals to phase zero and forget past unlocked steps,
+    /// keeping the configuration
+    pub fn reset(&mut self) {
+        self.golden_phase = 0.0;
+        self.silver_phase = 0.0;
+        self.unlocked_steps = 0;
+    }
+
+    /// Running count of unlocked steps from `validate_sequence_lenient`
+    pub fn unlocked_steps(&self) -> usize {
+        self.unlocked_steps
+    }
+
     /// Advance the dual spirals by a logic step
     pub fn advance(&mut self, step: f64) {
         // Golden winding (primary)
@@ -335,21 +1179,32 @@ impl PoincareSectionValidator {
         self.silver_phase = (self.silver_phase + step * DELTA) % (2.0 * PI);
     }
 
-    /// Check for chirality lock (both phases must be irrational)
-    /// Returns true if the phases create a valid Moire pattern
-    pub fn is_locked(&self) -> bool {
-        // The phases should never sync (irrational windings)
-        let phase_diff = (self.golden_phase - self.silver_phase).abs();
+    /// Absolute difference between the golden and silver phases
+    pub fn phase_diff(&self) -> f64 {
+        (self.golden_phase - self.silver_phase).abs()
+    }
 
-        // If too close to rational ratio, it's a lie trying to penetrate
-        for n in 1..10 {
+    /// Find the rational resonance `n·π/5` the phase difference sits on
+    /// Returns `(n, n·π/5)` when within tolerance, `None` when locked
+    pub fn nearest_resonance(&self) -> Option<(u32, f64)> {
+        let phase_diff = self.phase_diff();
+
+        for n in 1..self.max_rational_n {
             let rational = (n as f64) * PI / 5.0;
-            if (phase_diff - rational).abs() < 0.01 {
-                return false; // Rational resonance detected!
+            if (phase_diff - rational).abs() < self.resonance_tolerance {
+                return Some((n, rational));
             }
         }
 
-        true
+        None
+    }
+
+    /// Check for chirality lock (both phases must be irrational)
+    /// Returns true if the phases create a valid Moire pattern
+    pub fn is_locked(&self) -> bool {
+        // The phases should never sync (irrational windings).
+        // If too close to rational ratio, it's a lie trying to penetrate
+        self.nearest_resonance().is_none()
     }
 
     /// Validate a sequence of logic steps
@@ -362,6 +1217,23 @@ impl PoincareSectionValidator {
         }
         true
     }
+
+    /// Validate a sequence, tolerating transient resonance blips
+    /// Unlocked steps add to a running count that persists across calls, so
+    /// a stream can be fed in chunks; it fails once the count exceeds
+    /// `max_unlocked`. `reset` clears the count.
+    pub fn validate_sequence_lenient(&mut self, steps: &[f64], max_unlocked: usize) -> bool {
+        for step in steps {
+            self.advance(*step);
+            if !self.is_locked() {
+                self.unlocked_steps += 1;
+                if self.unlocked_steps > max_unlocked {
+                    return false;
+                }
+            }
+        }
+        true
+    }
 }
 
 // =============================================================================
@@ -369,6 +1241,13 @@ impl PoincareSectionValidator {
 // =============================================================================
 
 /// The breathing loop of logic time
+///
+/// With `half_twists` = n, a flip edge (subject/object inversion) occurs each
+/// time n·θ crosses an odd multiple of π, i.e. at θ = (2k + 1)π/n: n edges
+/// per revolution, and a single flip at θ = π for the classic one-twist band.
+/// Odd n leaves the narrative inverted after a full loop; even n flips back
+/// and forth and ends the loop as it started. Zero twists never flip.
+#[derive(Clone)]
 pub struct MobiusTorus {
     /// Current position on the torus (0 to 2π)
     pub theta: f64,
@@ -376,41 +1255,110 @@ pub struct MobiusTorus {
     pub phi: f64,
     /// Compression factor (high pressure inward)
     pub compression: f64,
-    /// Number of half-twists (Möbius property)
+    /// Number of half-twists (Möbius property): flip edges per revolution
     pub half_twists: u32,
+    /// Distance from the torus center to the tube center
+    pub r_major: f64,
+    /// Tube radius before compression
+    pub r_minor: f64,
+    /// Helix advance per unit of theta
+    pub phi_rate: f64,
+    /// Log every `advance` result to the trajectory (off by default)
+    pub record: bool,
+    /// Latched once a subject/object flip has occurred
+    flipped: bool,
+    /// Odd number of flip edges since the last reset
+    inverted: bool,
+    /// Recorded `advance` results, oldest first
+    trajectory: Vec<(Point3D, bool)>,
 }
 
 impl MobiusTorus {
     pub fn new(half_twists: u32) -> Self {
+        Self::with_geometry(half_twists, 2.0, 1.0, PHI)
+    }
+
+    /// Torus with custom radii and helix rate
+    pub fn with_geometry(half_twists: u32, r_major: f64, r_minor: f64, phi_rate: f64) -> Self {
         Self {
             theta: 0.0,
             phi: 0.0,
             compression: 1.0,
             half_twists,
+            r_major,
+            r_minor,
+            phi_rate,
+            record: false,
+            flipped: false,
+            inverted: false,
+            trajectory: Vec::new(),
         }
     }
 
+    /// Return to the start of the loop at neutral compression
+    pub fn reset(&mut self) {
+        self.theta = 0.0;
+        self.phi = 0.0;
+        self.compression = 1.0;
+        self.flipped = false;
+        self.inverted = false;
+    }
+
+    /// Whether a subject/object flip has occurred since the last reset
+    pub fn has_flipped(&self) -> bool {
+        self.flipped
+    }
+
+    /// Whether subject and object are currently swapped: an odd number of
+    /// flip edges since the last reset
+    pub fn is_inverted(&self) -> bool {
+        self.inverted
+    }
+
+    /// `advance` results recorded while `record` was on
+    /// Kept across `reset`; only `clear_trajectory` empties it.
+    pub fn trajectory(&self) -> &[(Point3D, bool)] {
+        &self.trajectory
+    }
+
+    pub fn clear_trajectory(&mut self) {
+        self.trajectory.clear();
+    }
+
     /// Advance along the Möbius surface
-    /// Returns (x, y, z) position and whether subject/object flipped
+    /// Returns (x, y, z) position and whether subject/object flipped on this
+    /// step; a step spanning several flip edges reports their net effect
     pub fn advance(&mut self, step: f64) -> (Point3D, bool) {
-        self.theta = (self.theta + step) % (2.0 * PI);
-        self.phi = (self.phi + step * PHI) % (2.0 * PI);
+        // Count crossings of n·θ = odd·π within (previous, previous + step]
+        let previous = self.theta;
+        let n = self.half_twists as f64;
+        let turns = |t: f64| ((n * t - PI) / (2.0 * PI)).floor();
+        let edges = (turns(previous + step) - turns(previous)) as i64;
+
+        self.theta = (previous + step) % (2.0 * PI);
+        self.phi = (self.phi + step * self.phi_rate) % (2.0 * PI);
 
         // Möbius twist angle
         let twist = (self.half_twists as f64) * self.theta / 2.0;
 
         // Torus coordinates with Möbius twist
-        let r_major = 2.0;
-        let r_minor = 1.0 * self.compression;
+        let r_major = self.r_major;
+        let r_minor = self.r_minor * self.compression;
 
         let x = (r_major + r_minor * twist.cos()) * self.theta.cos();
         let y = (r_major + r_minor * twist.cos()) * self.theta.sin();
         let z = r_minor * twist.sin();
 
-        // Subject becomes Object after half rotation with odd twists
-        let flipped = (self.half_twists % 2 == 1) && (self.theta > PI);
+        // Subject becomes Object at each edge; pairs of edges cancel
+        let flipped = edges % 2 != 0;
+        self.flipped |= edges != 0;
+        self.inverted ^= flipped;
 
-        (Point3D::new(x, y, z), flipped)
+        let sample = (Point3D::new(x, y, z), flipped);
+        if self.record {
+            self.trajectory.push(sample);
+        }
+        sample
     }
 
     /// Compress inward (analysis mode)
@@ -429,29 +1377,78 @@ impl MobiusTorus {
 // =============================================================================
 
 /// The complete truth validation engine
+#[derive(Clone)]
 pub struct UnifiedFieldValidator {
     pub geometry: NarrativeGeometry,
     pub poincare: PoincareSectionValidator,
     pub chronometer: MobiusTorus,
+    /// Run `normalize_narrative` on every input first (off by default)
+    pub normalize: bool,
+}
+
+/// Snapshot of a `UnifiedFieldValidator`'s evolving state, for keeping
+/// per-session validators across restarts
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ValidatorState {
+    pub golden_phase: f64,
+    pub silver_phase: f64,
+    pub unlocked_steps: usize,
+    pub theta: f64,
+    pub phi: f64,
+    pub compression: f64,
+    /// Chronometer flip latch (`MobiusTorus::has_flipped`)
+    pub flipped: bool,
+    /// Chronometer orientation (`MobiusTorus::is_inverted`)
+    pub inverted: bool,
+    /// Surface grid resolution
+    pub resolution: usize,
+    /// Surface parametric half-width
+    pub range: f64,
+}
+
+/// One word's share of a unified validation, for word-level highlighting
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WordAnnotation {
+    pub word: String,
+    /// Tension contributed by this word on the narrative surface
+    pub tension: f64,
+    /// Whether the Poincaré spirals were unlocked after this word's step
+    pub unlocked: bool,
 }
 
 impl UnifiedFieldValidator {
     pub fn new() -> Self {
-        Self {
-            geometry: NarrativeGeometry::new(32),
-            poincare: PoincareSectionValidator::new(),
-            chronometer: MobiusTorus::new(1), // Single Möbius twist
-        }
+        UnifiedFieldValidatorBuilder::default().build()
+    }
+
+    /// Configure a validator field by field, starting from the `new` defaults
+    pub fn builder() -> UnifiedFieldValidatorBuilder {
+        UnifiedFieldValidatorBuilder::default()
+    }
+
+    /// Confidence in `state`, 0–100, against this validator's thresholds
+    pub fn confidence(&self, state: &TruthState) -> f64 {
+        self.geometry.confidence(state)
     }
 
     /// Full validation of a narrative
     pub fn validate(&mut self, text: &str) -> TruthState {
+        let normalized;
+        let text = if self.normalize {
+            normalized = normalize_narrative(text);
+            normalized.as_str()
+        } else {
+            text
+        };
+
         // Step 1: Map to Enneper surface
         let geometry_state = self.geometry.map_narrative(text);
 
         // Step 2: Check Poincaré stability
         let words: Vec<&str> = text.split_whitespace().collect();
-        let steps: Vec<f64> = words.iter().map(|w| w.len() as f64 * 0.1).collect();
+        let steps = Self::poincare_steps(&words);
 
         let poincare_valid = self.poincare.validate_sequence(&steps);
 
@@ -466,11 +1463,14 @@ impl UnifiedFieldValidator {
 
         // Combine all validations
         match geometry_state {
-            TruthState::Crystal { curvature, tension } if poincare_valid => {
+            TruthState::Crystal { curvature, tension }
+                if poincare_valid && !conscience_triggered =>
+            {
                 TruthState::Crystal { curvature, tension }
             }
             TruthState::Crystal { curvature, tension } => {
-                // Geometry says truth but Poincaré failed
+                // Geometry says truth but Poincaré failed, or the Möbius
+                // flip inverted subject and object
                 TruthState::Annealing { curvature, tension }
             }
             TruthState::Annealing { curvature, tension } => {
@@ -479,6 +1479,263 @@ impl UnifiedFieldValidator {
             TruthState::Hallucination { curvature, tension } => {
                 TruthState::Hallucination { curvature, tension }
             }
+        }
+    }
+
+    /// Validate and attribute the result to individual words
+    /// The verdict and state changes match `validate`. Unlock flags are taken
+    /// from every step, including those after the first unlock. With
+    /// `normalize` set, the annotated words are the normalized ones.
+    pub fn validate_annotated(&mut self, text: &str) -> (TruthState, Vec<WordAnnotation>) {
+        let normalized;
+        let text = if self.normalize {
+            normalized = normalize_narrative(text);
+            normalized.as_str()
+        } else {
+            text
+        };
+        let words: Vec<&str> = text.split_whitespace().collect();
+
+        let mut probe = self.poincare.clone();
+        let unlocked: Vec<bool> = Self::poincare_steps(&words)
+            .into_iter()
+            .map(|step| {
+                probe.advance(step);
+                !probe.is_locked()
+            })
+            .collect();
+
+        let state = self.validate(text);
+        let annotations = words
+            .iter()
+            .zip(self.geometry.tension_field())
+            .zip(unlocked)
+            .map(|((word, &tension), unlocked)| WordAnnotation {
+                word: String::from(*word),
+                tension,
+                unlocked,
+            })
+            .collect();
+
+        (state, annotations)
+    }
+
+    /// Content-addressed ID of a narrative and its verdict
+    ///
+    /// Hashes the whitespace-normalized text (fully normalized with
+    /// `normalize` set) with the verdict, the curvature
+    /// and tension (quantized to `FINGERPRINT_QUANTUM`) and the Poincaré lock
+    /// result, as two FNV-1a lanes (seeds 0 and φ's bits). This is a content
+    /// digest rather than a word hash, so it keeps `Fnv1aHasher` whatever
+    /// `DefaultTentHasher` is. Poincaré and chronometer state are reset
+    /// first, as in `validate_batch`, so the ID depends only on the text and
+    /// the surface.
+    pub fn fingerprint(&mut self, text: &str) -> [u8; 16] {
+        let normalized = if self.normalize {
+            normalize_narrative(text)
+        } else {
+            text.split_whitespace().collect::<Vec<_>>().join(" ")
+        };
+        let words: Vec<&str> = normalized.split_whitespace().collect();
+
+        self.poincare.reset();
+        self.chronometer.reset();
+        let locked = self.poincare.clone().validate_sequence(&Self::poincare_steps(&words));
+        let state = self.validate(&normalized);
+
+        let verdict = match state {
+            TruthState::Crystal { .. } => 0u8,
+            TruthState::Annealing { .. } => 1,
+            TruthState::Hallucination { .. } => 2,
+        };
+        // Adding 0.0 folds -0.0 into 0.0
+        let quantize = |x: f64| ((x / FINGERPRINT_QUANTUM).round() + 0.0).to_bits();
+
+        let mut bytes = normalized.into_bytes();
+        bytes.push(verdict);
+        bytes.extend(quantize(state.curvature()).to_le_bytes());
+        bytes.extend(quantize(state.tension()).to_le_bytes());
+        bytes.push(locked as u8);
+
+        let hasher = Fnv1aHasher;
+        let mut id = [0u8; 16];
+        id[..8].copy_from_slice(&hasher.hash_bytes(&bytes, 0).to_be_bytes());
+        id[8..].copy_from_slice(&hasher.hash_bytes(&bytes, PHI.to_bits()).to_be_bytes());
+        id
+    }
+
+    /// Poincaré step per word, proportional to its length
+    fn poincare_steps(words: &[&str]) -> Vec<f64> {
+        words.iter().map(|w| text_len(w) as f64 * 0.1).collect()
+    }
+
+    /// Validate many narratives independently
+    /// Poincaré and chronometer state are reset before each item
+    pub fn validate_batch(&mut self, texts: &[&str]) -> Vec<TruthState> {
+        texts
+            .iter()
+            .map(|text| {
+                self.poincare.reset();
+                self.chronometer.reset();
+                self.validate(text)
+            })
+            .collect()
+    }
+
+    /// Capture the Poincaré phases, chronometer position and surface size
+    pub fn snapshot(&self) -> ValidatorState {
+        ValidatorState {
+            golden_phase: self.poincare.golden_phase,
+            silver_phase: self.poincare.silver_phase,
+            unlocked_steps: self.poincare.unlocked_steps,
+            theta: self.chronometer.theta,
+            phi: self.chronometer.phi,
+            compression: self.chronometer.compression,
+            flipped: self.chronometer.flipped,
+            inverted: self.chronometer.inverted,
+            resolution: self.geometry.surface().resolution,
+            range: self.geometry.surface().range,
+        }
+    }
+
+    /// Rebuild a validator from a snapshot
+    /// Only the snapshotted state carries over: tolerances, twist count,
+    /// weighting, thresholds and the like are the `new` defaults.
+    pub fn restore(state: ValidatorState) -> Self {
+        let mut validator = Self::new();
+        validator.geometry = NarrativeGeometry::with_range(state.resolution, state.range);
+        validator.poincare.golden_phase = state.golden_phase;
+        validator.poincare.silver_phase = state.silver_phase;
+        validator.poincare.unlocked_steps = state.unlocked_steps;
+        validator.chronometer.theta = state.theta;
+        validator.chronometer.phi = state.phi;
+        validator.chronometer.compression = state.compression;
+        validator.chronometer.flipped = state.flipped;
+        validator.chronometer.inverted = state.inverted;
+        validator
+    }
+
+    /// Validate against a copy of the current state, leaving self untouched
+    pub fn validate_isolated(&self, text: &str) -> TruthState {
+        self.clone().validate(text)
+    }
+}
+
+/// Builder for `UnifiedFieldValidator`; the defaults match
+/// `UnifiedFieldValidator::new`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnifiedFieldValidatorBuilder {
+    resolution: usize,
+    half_twists: u32,
+    thresholds: (f64, f64),
+    resonance_tolerance: f64,
+    normalize: bool,
+}
+
+impl Default for UnifiedFieldValidatorBuilder {
+    fn default() -> Self {
+        Self {
+            resolution: 32,
+            half_twists: 1, // Single Möbius twist
+            thresholds: DEFAULT_THRESHOLDS,
+            resonance_tolerance: PoincareSectionValidator::new().resonance_tolerance,
+            normalize: false,
+        }
+    }
+}
+
+impl UnifiedFieldValidatorBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enneper surface grid resolution
+    pub fn resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Chronometer half twists, see `MobiusTorus`
+    pub fn half_twists(mut self, half_twists: u32) -> Self {
+        self.half_twists = half_twists;
+        self
+    }
+
+    /// Average tension below which a narrative can crystallize
+    pub fn tension_threshold(mut self, threshold: f64) -> Self {
+        self.thresholds.0 = threshold;
+        self
+    }
+
+    /// Mean grid Laplacian magnitude of the surface
+    /// (`EnneperSurface::total_tension`) below which a narrative can crystallize
+    pub fn curvature_threshold(mut self, threshold: f64) -> Self {
+        self.thresholds.1 = threshold;
+        self
+    }
+
+    /// Poincaré resonance tolerance; larger rejects more sequences
+    pub fn resonance_tolerance(mut self, tolerance: f64) -> Self {
+        self.resonance_tolerance = tolerance;
+        self
+    }
+
+    /// Run `normalize_narrative` on every input first
+    pub fn normalize(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    pub fn build(self) -> UnifiedFieldValidator {
+        let mut geometry = NarrativeGeometry::new(self.resolution);
+        geometry.set_thresholds(self.thresholds);
+        let mut poincare = PoincareSectionValidator::new();
+        poincare.resonance_tolerance = self.resonance_tolerance;
+
+        UnifiedFieldValidator {
+            geometry,
+            poincare,
+            chronometer: MobiusTorus::new(self.half_twists),
+            normalize: self.normalize,
+        }
+    }
+}
+
+/// Shared, `Send + Sync` validator for concurrent callers (e.g. behind an
+/// `Arc` in a web server) with no `Mutex`
+///
+/// Holds a configured `UnifiedFieldValidator` as a read-only template; each
+/// `validate` clones it (sharing the surface `Arc`) and resets Poincaré and
+/// chronometer state first, so every call sees the same starting state and
+/// results match `UnifiedFieldValidator::validate_batch`.
+#[derive(Clone)]
+pub struct ThreadSafeValidator {
+    template: UnifiedFieldValidator,
+}
+
+impl Default for ThreadSafeValidator {
+    fn default() -> Self {
+        Self::from_validator(UnifiedFieldValidator::new())
+    }
+}
+
+impl ThreadSafeValidator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Share an existing validator's surface and configuration
+    pub fn from_validator(mut validator: UnifiedFieldValidator) -> Self {
+        validator.poincare.reset();
+        validator.chronometer.reset();
+        validator.chronometer.record = false;
+        validator.chronometer.clear_trajectory();
+        Self { template: validator }
+    }
+
+    /// Validate with fresh per-call state
+    pub fn validate(&self, text: &str) -> TruthState {
+        self.template.clone().validate(text)
     }
 }
 
@@ -487,9 +1744,11 @@ impl UnifiedFieldValidator {
 // =============================================================================
 
 /// Result of implicit differentiation at a point
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ImplicitDerivative {
     /// dy/dx value (may be infinite)
+    #[cfg_attr(feature = "serde", serde(with = "serde_float"))]
     pub slope: f64,
     /// Whether the derivative is bounded
     pub is_bounded: bool,
@@ -501,6 +1760,16 @@ pub struct ImplicitDerivative {
     pub df_dy: f64,
 }
 
+/// A point on a Poincaré section with its tangent slope
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PoincarePoint {
+    pub x: f64,
+    pub y: f64,
+    /// dy/dx, or None at a vertical tangent
+    pub slope: Option<f64>,
+}
+
 /// Validates narratives using implicit differentiation
 /// Detects "tear points" where the surface becomes undefined
 pub struct ImplicitSurfaceValidator {
@@ -518,12 +1787,32 @@ impl ImplicitSurfaceValidator {
         }
     }
     
+    /// Validator with a custom slope magnitude for tears
+    pub fn with_tear_threshold(resolution: usize, threshold: f64) -> Result<Self, &'static str> {
+        let mut validator = Self::new(resolution);
+        validator.set_tear_threshold(threshold)?;
+        Ok(validator)
+    }
+    
+    /// Change the slope magnitude above which a point counts as torn
+    pub fn set_tear_threshold(&mut self, threshold: f64) -> Result<(), &'static str> {
+        if !(threshold.is_finite() && threshold > 0.0) {
+            return Err("tear_threshold must be positive and finite");
+        }
+        self.tear_threshold = threshold;
+        Ok(())
+    }
+    
+    pub fn tear_threshold(&self) -> f64 {
+        self.tear_threshold
+    }
+    
     /// Compute implicit derivative at a surface point
     /// For the Enneper surface, we use the constraint H(x,y,z) = 0
     /// dy/dx = -(∂H/∂x) / (∂H/∂y)
     pub fn implicit_derivative(&self, i: usize, j: usize) -> ImplicitDerivative {
-        if i == 0 || i >= self.surface.resolution - 1 || 
-           j == 0 || j >= self.surface.resolution - 1 {
+        let last = self.surface.resolution.saturating_sub(1);
+        if i == 0 || i >= last || j == 0 || j >= last {
             return ImplicitDerivative {
                 slope: 0.0,
                 is_bounded: true,
@@ -547,8 +1836,8 @@ impl ImplicitSurfaceValidator {
         let df_dx = (right.x - left.x) / 2.0;
         let df_dy = (down.y - up.y) / 2.0;
         
-        // Check for tear point (denominator near zero)
-        let is_tear = df_dy.abs() < 0.001;
+        // Check for tear point (denominator near zero, or undefined partials)
+        let is_tear = df_dy.abs() < 0.001 || !df_dx.is_finite() || !df_dy.is_finite();
         
         let slope = if is_tear {
             f64::INFINITY
@@ -575,7 +1864,7 @@ impl ImplicitSurfaceValidator {
         
         for (idx, word) in words.iter().enumerate() {
             // Hash word to surface position
-            let hash = self.word_hash(word);
+            let hash = narrative_hash(word, 0);
             let i = (idx % (self.surface.resolution - 2)) + 1;
             let j = ((hash % self.surface.resolution as u64) as usize).max(1).min(self.surface.resolution - 2);
             
@@ -591,24 +1880,18 @@ impl ImplicitSurfaceValidator {
         (derivatives, has_tear)
     }
     
-    /// Compute hash for word (same as NarrativeGeometry)
-    fn word_hash(&self, word: &str) -> u64 {
-        let primes = [2u64, 3, 5, 7, 11, 13, 17, 19, 23, 29];
-        let mut hash = 0u64;
-        
-        for (i, c) in word.chars().enumerate() {
-            let prime = primes[i % primes.len()];
-            hash = hash.wrapping_add((c as u64).wrapping_mul(prime));
-        }
-        
-        hash
+    /// Get the Poincaré section (cross-section of torus)
+    /// Returns the circle x² + y² = r², the same at every section angle
+    pub fn poincare_section(&self, r: f64) -> Vec<PoincarePoint> {
+        self.poincare_section_with_steps(r, 100)
     }
     
-    /// Get the Poincaré section (cross-section of torus)
-    /// Returns the circle x² + y² = r² at a given angle
-    pub fn poincare_section(&self, theta: f64, r: f64) -> Vec<(f64, f64, f64)> {
-        let mut points = Vec::new();
-        let steps = 100;
+    /// `poincare_section` sampled at `steps` evenly spaced angles
+    /// A sample counts as a pole when |y| is within half the height of its
+    /// neighbouring sample, so the test scales with r and the step size
+    pub fn poincare_section_with_steps(&self, r: f64, steps: usize) -> Vec<PoincarePoint> {
+        let mut points = Vec::with_capacity(steps);
+        let pole_tolerance = r.abs() * (PI / steps as f64).sin() / 2.0;
         
         for k in 0..steps {
             let phi = (k as f64 / steps as f64) * 2.0 * PI;
@@ -617,14 +1900,14 @@ impl ImplicitSurfaceValidator {
             let x = r * phi.cos();
             let y = r * phi.sin();
             
-            // Implicit derivative: dy/dx = -x/y
-            let slope = if y.abs() < 0.001 {
-                f64::INFINITY
+            // Implicit derivative: dy/dx = -x/y (vertical where y = 0)
+            let slope = if y.abs() <= pole_tolerance {
+                None
             } else {
-                -x / y
+                Some(-x / y)
             };
             
-            points.push((x, y, slope));
+            points.push(PoincarePoint { x, y, slope });
         }
         
         points
@@ -643,14 +1926,20 @@ pub struct EntangledFluxValidator {
     silver_phase: f64,
     /// Step size
     step: f64,
+    /// (product, derivative) recorded at each advance
+    history: Vec<(f64, f64)>,
 }
 
+/// Largest |d/dx[φ·δ]| still considered stable
+const FLUX_STABILITY_BOUND: f64 = 10.0;
+
 impl EntangledFluxValidator {
     pub fn new() -> Self {
         Self {
             golden_phase: 0.0,
             silver_phase: 0.0,
             step: 0.01,
+            history: Vec::new(),
         }
     }
     
@@ -677,13 +1966,27 @@ impl EntangledFluxValidator {
         self.golden_phase = (self.golden_phase + step * PHI) % (2.0 * PI);
         self.silver_phase = (self.silver_phase + step * DELTA) % (2.0 * PI);
         
-        (self.product(), self.product_derivative())
+        let sample = (self.product(), self.product_derivative());
+        self.history.push(sample);
+        sample
     }
     
     /// Check if the product derivative indicates entanglement stability
     pub fn is_stable(&self) -> bool {
         let deriv = self.product_derivative();
-        deriv.abs() < 10.0 // Bounded derivative = stable
+        deriv.abs() < FLUX_STABILITY_BOUND // Bounded derivative = stable
+    }
+    
+    /// (product, derivative) for every step advanced so far
+    pub fn history(&self) -> &[(f64, f64)] {
+        &self.history
+    }
+    
+    /// Step index where the derivative first left the stable bound
+    pub fn first_instability(&self) -> Option<usize> {
+        self.history
+            .iter()
+            .position(|&(_, deriv)| deriv.abs() >= FLUX_STABILITY_BOUND)
     }
 }
 
@@ -704,6 +2007,7 @@ impl EntangledFluxValidator {
 
 /// Classification of geometric curvature type
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CurvatureType {
     /// Positive curvature - sphere-like (Truth gravitates inward)
     Spherical,
@@ -713,16 +2017,104 @@ pub enum CurvatureType {
     Hyperbolic,
 }
 
+impl CurvatureType {
+    /// Ordering from most truth-like (2) to most lie-like (0)
+    fn rank(self) -> u8 {
+        match self {
+            CurvatureType::Spherical => 2,
+            CurvatureType::Flat => 1,
+            CurvatureType::Hyperbolic => 0,
+        }
+    }
+}
+
+/// Change between two pseudosphere analyses (`before.delta(&after)`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnalysisDelta {
+    /// after - before; positive = more "excuses" per unit of substance
+    #[cfg_attr(feature = "serde", serde(with = "serde_float"))]
+    pub horn_ratio_change: f64,
+    /// after - before; negative = bending toward the pseudosphere
+    pub curvature_change: f64,
+    pub from_type: CurvatureType,
+    pub to_type: CurvatureType,
+}
+
+impl AnalysisDelta {
+    /// Whether the curvature crossed into a different category
+    pub fn curvature_type_changed(&self) -> bool {
+        self.from_type != self.to_type
+    }
+
+    /// True if the geometry became more lie-like
+    ///
+    /// A drop in curvature category always counts; within the same
+    /// category the horn ratio must grow without the curvature improving.
+    pub fn got_worse(&self) -> bool {
+        let (from, to) = (self.from_type.rank(), self.to_type.rank());
+        if from != to {
+            return to < from;
+        }
+        self.horn_ratio_change > 0.0 && self.curvature_change <= 0.0
+    }
+}
+
 /// Result of pseudosphere analysis
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PseudosphereAnalysis {
     pub curvature_type: CurvatureType,
+    #[cfg_attr(feature = "serde", serde(with = "serde_float"))]
     pub gaussian_curvature: f64,      // K value
     pub volume_estimate: f64,          // "Substance"
     pub surface_estimate: f64,         // "Excuses"
+    #[cfg_attr(feature = "serde", serde(with = "serde_float"))]
     pub gabriels_horn_ratio: f64,      // Surface/Volume (>1 = suspect)
     pub singularity_detected: bool,    // Hit the "rim"
     pub is_lie_geometry: bool,         // Final verdict
+    pub horn_lie_ratio: f64,           // Horn ratio cutoff applied
+}
+
+/// Why a narrative was flagged as lie geometry
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LieReason {
+    /// Repetitive text curved the surface negatively
+    HyperbolicCurvature,
+    /// Surface/Volume ratio above the lie cutoff (carries the ratio)
+    ExcessiveHornRatio(f64),
+    /// Infinite surface with near-zero volume
+    Singularity,
+}
+
+impl PseudosphereAnalysis {
+    /// Compare against a later analysis of the same (edited) narrative
+    pub fn delta(&self, other: &Self) -> AnalysisDelta {
+        AnalysisDelta {
+            horn_ratio_change: other.gabriels_horn_ratio - self.gabriels_horn_ratio,
+            curvature_change: other.gaussian_curvature - self.gaussian_curvature,
+            from_type: self.curvature_type,
+            to_type: other.curvature_type,
+        }
+    }
+
+    /// The individual signals behind `is_lie_geometry` (empty = clean)
+    pub fn reasons(&self) -> Vec<LieReason> {
+        let mut reasons = Vec::new();
+
+        if self.curvature_type == CurvatureType::Hyperbolic {
+            reasons.push(LieReason::HyperbolicCurvature);
+        }
+        if self.gabriels_horn_ratio > self.horn_lie_ratio {
+            reasons.push(LieReason::ExcessiveHornRatio(self.gabriels_horn_ratio));
+        }
+        if self.singularity_detected {
+            reasons.push(LieReason::Singularity);
+        }
+
+        reasons
+    }
 }
 
 /// The Tractrix curve - generator of the Pseudosphere
@@ -738,6 +2130,11 @@ pub struct Tractrix {
 }
 
 impl Tractrix {
+    /// `t_for_x` stops once a step moves t by less than this
+    pub const INVERSE_TOLERANCE: f64 = 1e-12;
+    /// `t_for_x` gives up after this many iterations
+    pub const INVERSE_MAX_ITERATIONS: usize = 100;
+
     pub fn new(resolution: usize) -> Self {
         Self { resolution }
     }
@@ -755,6 +2152,48 @@ impl Tractrix {
         (x, y)
     }
     
+    /// Invert x(t) = t - tanh(t) on t ≥ 0, where it increases monotonically
+    /// 
+    /// Newton steps (dx/dt = tanh²t) safeguarded by bisection on [0, x + 1]
+    /// (x(t) ≥ t - 1 bounds the root). Converges when a step moves t by less
+    /// than `INVERSE_TOLERANCE`; returns the best estimate after
+    /// `INVERSE_MAX_ITERATIONS`. `None` for negative or non-finite x.
+    /// Note `point` clamps t ≤ 0.001, so round trips hold above that.
+    pub fn t_for_x(&self, x: f64) -> Option<f64> {
+        if !x.is_finite() || x < 0.0 {
+            return None;
+        }
+        if x == 0.0 {
+            return Some(0.0);
+        }
+        
+        let (mut lo, mut hi) = (0.0, x + 1.0);
+        let mut t = 0.5 * (lo + hi);
+        for _ in 0..Self::INVERSE_MAX_ITERATIONS {
+            let f = t - t.tanh() - x;
+            if f > 0.0 {
+                hi = t;
+            } else {
+                lo = t;
+            }
+            
+            let slope = t.tanh().powi(2);
+            let newton = t - f / slope;
+            let next = if slope > 0.0 && newton > lo && newton < hi {
+                newton
+            } else {
+                0.5 * (lo + hi)
+            };
+            
+            let step = (next - t).abs();
+            t = next;
+            if f == 0.0 || step < Self::INVERSE_TOLERANCE {
+                break;
+            }
+        }
+        Some(t)
+    }
+    
     /// Calculate arc length element ds
     pub fn arc_element(&self, t: f64) -> f64 {
         if t <= 0.001 {
@@ -767,6 +2206,26 @@ impl Tractrix {
         (sech * tanh).abs()
     }
     
+    /// Arc length between t0 and t1: ∫ sech(t)·tanh(t) dt
+    /// Integrated with composite Simpson's rule over `resolution` panels
+    pub fn arc_length(&self, t0: f64, t1: f64) -> f64 {
+        if t1 <= t0 {
+            return 0.0;
+        }
+        
+        // Simpson needs an even panel count
+        let n = (self.resolution.max(2) + 1) & !1;
+        let h = (t1 - t0) / n as f64;
+        let mut sum = self.arc_element(t0) + self.arc_element(t1);
+        
+        for k in 1..n {
+            let weight = if k % 2 == 1 { 4.0 } else { 2.0 };
+            sum += weight * self.arc_element(t0 + k as f64 * h);
+        }
+        
+        sum * h / 3.0
+    }
+    
     /// Detect if we've hit the singularity (the "rim")
     pub fn is_at_singularity(&self, t: f64) -> bool {
         // The Tractrix has a cusp at t → ∞ (y → 0)
@@ -809,23 +2268,47 @@ impl Pseudosphere {
         (2.0 / 3.0) * PI * self.radius.powi(3)
     }
     
+    /// Volume of the horn truncated at `t_max` (same truncation as
+    /// `surface_area`): V = π ∫ y(t)²·(dx/dt) dt over (0, t_max].
+    /// One horn tends to half of `volume()` as t_max → ∞.
+    pub fn volume_to(&self, t_max: f64) -> f64 {
+        let steps = self.tractrix.resolution.max(1);
+        let dt = t_max / steps as f64;
+        let mut volume = 0.0;
+        
+        // Midpoint Riemann sum, dx/dt = 1 - sech²(t) = tanh²(t)
+        for i in 0..steps {
+            let t = (i as f64 + 0.5) * dt;
+            let (_, y) = self.tractrix.point(t);
+            let dx_dt = t.tanh().powi(2);
+            
+            volume += PI * y * y * dx_dt * dt;
+        }
+        
+        volume * self.radius.powi(3)
+    }
+    
     /// Estimate surface area (infinite in limit!)
-    /// For practical purposes, we integrate up to parameter t_max
+    /// 
+    /// `t_max` is the Tractrix parameter where the horn is truncated: the
+    /// surface runs from the neck (t → 0) out to axial length
+    /// x(t_max) = t_max - tanh(t_max). Larger `t_max` = a longer horn.
+    /// Scaled by radius², as `volume_to` is by radius³.
     pub fn surface_area(&self, t_max: f64) -> f64 {
-        let steps = self.tractrix.resolution;
+        let steps = self.tractrix.resolution.max(1);
         let dt = t_max / steps as f64;
         let mut area = 0.0;
         
-        for i in 1..steps {
-            let t = i as f64 * dt;
+        // Midpoint Riemann sum of dA = 2π·y·(ds/dt)·dt
+        for i in 0..steps {
+            let t = (i as f64 + 0.5) * dt;
             let (_, y) = self.tractrix.point(t);
-            let ds = self.tractrix.arc_element(t);
+            let ds_dt = self.tractrix.arc_element(t);
             
-            // Surface of revolution: dA = 2πy ds
-            area += 2.0 * PI * y * ds * dt;
+            area += 2.0 * PI * y * ds_dt * dt;
         }
         
-        area
+        area * self.radius.powi(2)
     }
     
     /// Gabriel's Horn Ratio: Surface / Volume
@@ -833,7 +2316,7 @@ impl Pseudosphere {
     /// - Ratio 1-10: Moderate (could be complex but honest)
     /// - Ratio < 1: Dense (solid substance)
     pub fn gabriels_horn_ratio(&self, t_max: f64) -> f64 {
-        let vol = self.volume();
+        let vol = self.volume_to(t_max);
         let surf = self.surface_area(t_max);
         
         if vol < 0.001 {
@@ -842,19 +2325,97 @@ impl Pseudosphere {
         
         surf / vol
     }
+
+    /// Export the surface of revolution as Wavefront OBJ
+    /// Rings follow the Tractrix over (0, t_max]; faces touching the
+    /// singular rim or the cusp are skipped.
+    pub fn to_obj(&self, t_max: f64, angular_steps: usize) -> String {
+        let mut obj = String::new();
+        let rings = self.tractrix.resolution.max(1);
+        let sectors = angular_steps.max(3);
+
+        let mut singular = Vec::with_capacity(rings);
+        for k in 1..=rings {
+            let t = t_max * k as f64 / rings as f64;
+            let (x, y) = self.tractrix.point(t);
+            singular.push(self.tractrix.is_at_singularity(t));
+
+            for a in 0..sectors {
+                let angle = 2.0 * PI * a as f64 / sectors as f64;
+                let r = self.radius * y;
+                let (vy, vz) = (r * angle.cos(), r * angle.sin());
+                let _ = writeln!(obj, "v {} {} {}", self.radius * x, vy, vz);
+            }
+        }
+
+        for k in 0..rings - 1 {
+            if singular[k] || singular[k + 1] {
+                continue;
+            }
+            for a in 0..sectors {
+                let next = (a + 1) % sectors;
+                let v1 = k * sectors + a + 1;
+                let v2 = (k + 1) * sectors + a + 1;
+                let v3 = (k + 1) * sectors + next + 1;
+                let v4 = k * sectors + next + 1;
+                let _ = writeln!(obj, "f {} {} {} {}", v1, v2, v3, v4);
+            }
+        }
+
+        obj
+    }
+}
+
+/// Thresholds used by the GeometricLieDetector
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DetectorConfig {
+    /// Uniqueness above this is Spherical (converging truth)
+    pub spherical_uniqueness: f64,
+    /// Uniqueness above this (and below spherical) is Flat
+    pub flat_uniqueness: f64,
+    /// Surface/Volume ratio above this flags a lie
+    pub horn_lie_ratio: f64,
+    /// Surface/Volume ratio above this is a singularity
+    pub singularity_ratio: f64,
+    /// Run `normalize_narrative` on the text before analysis
+    pub normalize: bool,
+}
+
+impl Default for DetectorConfig {
+    fn default() -> Self {
+        Self {
+            spherical_uniqueness: 0.7,
+            flat_uniqueness: 0.4,
+            horn_lie_ratio: 20.0,
+            singularity_ratio: 50.0,
+            normalize: false,
+        }
+    }
 }
 
 /// The GeometricLieDetector - uses curvature to classify narratives
 pub struct GeometricLieDetector {
     pseudosphere: Pseudosphere,
+    config: DetectorConfig,
 }
 
 impl GeometricLieDetector {
     pub fn new() -> Self {
+        Self::with_config(DetectorConfig::default())
+    }
+
+    /// Create a detector with custom thresholds
+    pub fn with_config(config: DetectorConfig) -> Self {
         Self {
             pseudosphere: Pseudosphere::new(64),
+            config,
         }
     }
+
+    /// The thresholds in use
+    pub fn config(&self) -> &DetectorConfig {
+        &self.config
+    }
     
     /// Analyze a narrative's "geometric signature"
     /// 
@@ -863,9 +2424,16 @@ impl GeometricLieDetector {
     /// - Character count → Surface (coverage)
     /// - Unique words / Total words → Curvature type
     pub fn analyze(&self, text: &str) -> PseudosphereAnalysis {
+        let normalized;
+        let text = if self.config.normalize {
+            normalized = normalize_narrative(text);
+            normalized.as_str()
+        } else {
+            text
+        };
         let words: Vec<&str> = text.split_whitespace().collect();
         let word_count = words.len() as f64;
-        let char_count = text.len() as f64;
+        let char_count = text_len(text) as f64;
         
         if word_count < 1.0 {
             return PseudosphereAnalysis {
@@ -876,13 +2444,12 @@ impl GeometricLieDetector {
                 gabriels_horn_ratio: 0.0,
                 singularity_detected: false,
                 is_lie_geometry: false,
+                horn_lie_ratio: self.config.horn_lie_ratio,
             };
         }
         
         // Unique words represent "real substance"
-        let unique_words: std::collections::HashSet<&str> = 
-            words.iter().map(|w| *w).collect();
-        let unique_count = unique_words.len() as f64;
+        let unique_count = unique_word_count(&words) as f64;
         
         // Uniqueness ratio determines curvature type
         let uniqueness = unique_count / word_count;
@@ -897,10 +2464,11 @@ impl GeometricLieDetector {
         let gabriels_horn_ratio = surface_estimate / volume_estimate.max(1.0);
         
         // Curvature classification
-        let (curvature_type, gaussian_curvature) = if uniqueness > 0.7 {
+        let config = &self.config;
+        let (curvature_type, gaussian_curvature) = if uniqueness > config.spherical_uniqueness {
             // High uniqueness = spherical (converging truth)
             (CurvatureType::Spherical, uniqueness)
-        } else if uniqueness > 0.4 {
+        } else if uniqueness > config.flat_uniqueness {
             // Medium uniqueness = flat
             (CurvatureType::Flat, 0.0)
         } else {
@@ -909,22 +2477,171 @@ impl GeometricLieDetector {
         };
         
         // Singularity detection: infinite surface with near-zero volume
-        let singularity_detected = gabriels_horn_ratio > 50.0;
-        
-        // Final lie detection using geometric signature
-        let is_lie_geometry = 
-            curvature_type == CurvatureType::Hyperbolic 
-            || gabriels_horn_ratio > 20.0
-            || singularity_detected;
+        let singularity_detected = gabriels_horn_ratio > config.singularity_ratio;
         
-        PseudosphereAnalysis {
+        let mut analysis = PseudosphereAnalysis {
             curvature_type,
             gaussian_curvature,
             volume_estimate,
             surface_estimate,
             gabriels_horn_ratio,
             singularity_detected,
-            is_lie_geometry,
+            is_lie_geometry: false,
+            horn_lie_ratio: config.horn_lie_ratio,
+        };
+        
+        // Final lie detection: any geometric reason flags the narrative
+        analysis.is_lie_geometry = !analysis.reasons().is_empty();
+        
+        analysis
+    }
+    
+    /// Analyze consecutive, non-overlapping windows of `window_words` words
+    pub fn analyze_windows(&self, text: &str, window_words: usize) -> Vec<PseudosphereAnalysis> {
+        self.analyze_windows_with_step(text, window_words, window_words)
+    }
+    
+    /// Slide a window of `window_words` words across the text, `step` words
+    /// at a time (a step below the window size overlaps windows)
+    /// 
+    /// Each window's words are rejoined with single spaces and analyzed on
+    /// their own; the last window is cut short at the end of the text.
+    pub fn analyze_windows_with_step(
+        &self,
+        text: &str,
+        window_words: usize,
+        step: usize,
+    ) -> Vec<PseudosphereAnalysis> {
+        let words: Vec<&str> = text.split_whitespace().collect();
+        let (window_words, step) = (window_words.max(1), step.max(1));
+        
+        let mut windows = Vec::new();
+        let mut start = 0;
+        while start < words.len() {
+            let end = (start + window_words).min(words.len());
+            windows.push(self.analyze(&words[start..end].join(" ")));
+            if end == words.len() {
+                break;
+            }
+            start += step;
+        }
+        windows
+    }
+}
+
+// =============================================================================
+// TENT REPORT: The Front Door
+// =============================================================================
+
+/// Weight of the Enneper/Poincaré/Möbius verdict in `TentReport::score`
+pub const REPORT_GEOMETRY_WEIGHT: f64 = 0.5;
+
+/// Weight of a clean pseudosphere analysis in `TentReport::score`
+pub const REPORT_PSEUDOSPHERE_WEIGHT: f64 = 0.3;
+
+/// Weight of the tear-free fraction of words in `TentReport::score`
+pub const REPORT_TEAR_WEIGHT: f64 = 0.2;
+
+/// Minimum score for `TentReport::is_truth`
+pub const REPORT_TRUTH_THRESHOLD: f64 = 0.6;
+
+/// Every detector's view of one narrative
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TentReport {
+    /// UnifiedFieldValidator verdict
+    pub geometry: TruthState,
+    /// GeometricLieDetector analysis
+    pub pseudosphere: PseudosphereAnalysis,
+    /// Words that landed on a tear point
+    pub tear_count: usize,
+    /// Words analyzed for tears
+    pub word_count: usize,
+    /// Weighted combination of the three detectors (0.0 - 1.0)
+    pub score: f64,
+    /// Combined verdict: score ≥ REPORT_TRUTH_THRESHOLD
+    pub is_truth: bool,
+}
+
+/// Run every detector on a narrative and aggregate the results
+///
+/// score = 0.5 · geometry (1 − surface tension, floored at 0)
+///       + 0.3 · pseudosphere (1 if no lie geometry)
+///       + 0.2 · fraction of words without a tear
+pub fn analyze(text: &str) -> TentReport {
+    let geometry = UnifiedFieldValidator::new().validate(text);
+    let pseudosphere = GeometricLieDetector::new().analyze(text);
+    let (derivatives, _) = ImplicitSurfaceValidator::new(32).validate_narrative(text);
+
+    let word_count = derivatives.len();
+    let tear_count = derivatives
+        .iter()
+        .filter(|d| d.is_tear || !d.is_bounded)
+        .count();
+
+    let geometry_score = (1.0 - geometry.tension()).clamp(0.0, 1.0);
+    let pseudosphere_score = if pseudosphere.is_lie_geometry { 0.0 } else { 1.0 };
+    let tear_score = if word_count == 0 {
+        1.0
+    } else {
+        1.0 - tear_count as f64 / word_count as f64
+    };
+
+    let score = REPORT_GEOMETRY_WEIGHT * geometry_score
+        + REPORT_PSEUDOSPHERE_WEIGHT * pseudosphere_score
+        + REPORT_TEAR_WEIGHT * tear_score;
+
+    TentReport {
+        geometry,
+        pseudosphere,
+        tear_count,
+        word_count,
+        score,
+        is_truth: score >= REPORT_TRUTH_THRESHOLD,
+    }
+}
+
+// =============================================================================
+// SERDE HELPERS
+// =============================================================================
+
+/// Serializes non-finite floats as the strings `"Infinity"`, `"-Infinity"`
+/// and `"NaN"` so the output stays valid JSON.
+#[cfg(feature = "serde")]
+mod serde_float {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{format, string::String};
+
+    pub fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        if value.is_finite() {
+            serializer.serialize_f64(*value)
+        } else if value.is_nan() {
+            serializer.serialize_str("NaN")
+        } else if *value > 0.0 {
+            serializer.serialize_str("Infinity")
+        } else {
+            serializer.serialize_str("-Infinity")
+        }
+    }
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Repr {
+        Number(f64),
+        Text(String),
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        match Repr::deserialize(deserializer)? {
+            Repr::Number(n) => Ok(n),
+            Repr::Text(s) => match s.as_str() {
+                "Infinity" => Ok(f64::INFINITY),
+                "-Infinity" => Ok(f64::NEG_INFINITY),
+                "NaN" => Ok(f64::NAN),
+                other => Err(serde::de::Error::custom(format!("invalid float: {}", other))),
+            },
         }
     }
 }
@@ -944,6 +2661,12 @@ mod tests {
 
         // Enneper surface should have near-zero tension (minimal surface)
         assert!(tension < 1.0, "Enneper tension should be low: {}", tension);
+
+        // Analytically the mean curvature vanishes everywhere
+        for &(u, v) in &[(0.0, 0.0), (0.5, -0.3), (1.2, 0.7), (-1.9, 1.9)] {
+            let h = surface.mean_curvature_analytic(u, v);
+            assert!(h.abs() < 1e-9, "H({}, {}) = {}", u, v, h);
+        }
     }
 
     #[test]
@@ -980,4 +2703,1170 @@ mod tests {
 
         panic!("Möbius flip should have occurred");
     }
+
+    #[test]
+    fn test_analyze_report() {
+        let truth = analyze("The derivative of x squared is two x");
+        let fluff = analyze("synergy synergy leverage synergy leverage synergy leverage synergy");
+
+        assert!(truth.is_truth);
+        assert!(!fluff.is_truth);
+        assert!(fluff.pseudosphere.is_lie_geometry);
+        assert!(truth.score > fluff.score);
+        assert_eq!(truth.word_count, 8);
+    }
+
+    #[test]
+    fn test_tear_threshold() {
+        let text = "The quick brown fox jumps over the lazy dog";
+
+        let strict = ImplicitSurfaceValidator::with_tear_threshold(32, 0.01).unwrap();
+        let (_, torn) = strict.validate_narrative(text);
+        assert!(torn);
+
+        let mut lenient = ImplicitSurfaceValidator::new(32);
+        lenient.set_tear_threshold(1e9).unwrap();
+        let (_, torn) = lenient.validate_narrative(text);
+        assert!(!torn);
+
+        assert!(lenient.set_tear_threshold(0.0).is_err());
+        assert!(lenient.set_tear_threshold(f64::NAN).is_err());
+        assert!(ImplicitSurfaceValidator::with_tear_threshold(32, f64::INFINITY).is_err());
+        assert_eq!(lenient.tear_threshold(), 1e9);
+    }
+
+    #[test]
+    fn test_poincare_section_slopes() {
+        let validator = ImplicitSurfaceValidator::new(16);
+        let section = validator.poincare_section(2.0);
+        assert_eq!(section.len(), 100);
+
+        // Vertical tangents only where the circle crosses y = 0 (φ = 0 and φ = π)
+        let vertical: Vec<usize> = section
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.slope.is_none())
+            .map(|(k, _)| k)
+            .collect();
+        assert_eq!(vertical, vec![0, 50]);
+        assert!(section.iter().filter_map(|p| p.slope).all(f64::is_finite));
+
+        assert_eq!(validator.poincare_section_with_steps(1.0, 12).len(), 12);
+
+        // The pole test scales: tiny circles and coarse or fine sampling
+        // still find exactly the two poles
+        for &(r, steps) in &[(1e-4, 100), (5.0, 8), (1.0, 4000)] {
+            let section = validator.poincare_section_with_steps(r, steps);
+            let vertical: Vec<usize> = (0..steps).filter(|&k| section[k].slope.is_none()).collect();
+            assert_eq!(vertical, vec![0, steps / 2], "r = {}, steps = {}", r, steps);
+        }
+    }
+
+    #[test]
+    fn test_degenerate_input_stays_finite() {
+        let zero = Point3D::new(0.0, 0.0, 0.0).normalize();
+        assert_eq!(zero, Point3D::new(0.0, 0.0, 0.0));
+        assert!(Point3D::new(f64::NAN, 1.0, 0.0).normalize().is_finite());
+        assert!(Point3D::new(f64::INFINITY, 1.0, 0.0).normalize().is_finite());
+        assert!(!Point3D::new(f64::NAN, 0.0, 0.0).is_finite());
+
+        let surface = EnneperSurface::new(8);
+        assert_eq!(surface.mean_curvature_analytic(f64::NAN, 0.0), 0.0);
+        assert_eq!(surface.mean_curvature_analytic(f64::INFINITY, 1.0), 0.0);
+
+        // A NaN surface classifies as Hallucination without leaking NaN
+        let mut geometry = NarrativeGeometry::with_range(8, f64::NAN);
+        let state = geometry.map_narrative("all zero narrative");
+        assert!(matches!(state, TruthState::Hallucination { .. }));
+        assert!(!state.tension().is_nan() && !state.curvature().is_nan());
+
+        let state = classify(f64::NAN, 0.0, DEFAULT_THRESHOLDS);
+        assert!(matches!(state, TruthState::Hallucination { .. }));
+        assert!(!state.tension().is_nan());
+
+        // Grid edges have no neighbours and report zero
+        let tiny = EnneperSurface::new(3);
+        assert_eq!(tiny.discrete_tension(0, 0), 0.0);
+        let slope = ImplicitSurfaceValidator::new(3).implicit_derivative(0, 0).slope;
+        assert!(slope.is_finite());
+    }
+
+    #[test]
+    fn test_minimum_resolution() {
+        for resolution in 0..=3 {
+            let surface = EnneperSurface::new(resolution);
+            assert_eq!(surface.resolution, MIN_RESOLUTION);
+            assert_eq!(surface.points.len(), MIN_RESOLUTION);
+            assert!(surface.total_tension().is_finite());
+
+            let mut geometry = NarrativeGeometry::new(resolution);
+            let state = geometry.map_narrative("small grids still validate");
+            assert!(state.tension().is_finite());
+
+            let mut stream = geometry.stream();
+            stream.push_word("small");
+            stream.push_word("grids");
+            assert!(stream.current_state().tension().is_finite());
+
+            let (derivatives, _) =
+                ImplicitSurfaceValidator::new(resolution).validate_narrative("small grids");
+            assert_eq!(derivatives.len(), 2);
+        }
+    }
+
+    #[test]
+    fn test_point_and_normal_at() {
+        let surface = EnneperSurface::new(10);
+        let last = surface.resolution - 1;
+
+        for (i, j) in [(0, 0), (3, 7), (last, last)] {
+            assert_eq!(surface.point_at(i, j), Some(&surface.points[i][j]));
+            assert_eq!(surface.normal_at(i, j), Some(&surface.normals[i][j]));
+        }
+        for (i, j) in [(last + 1, 0), (0, last + 1), (usize::MAX, usize::MAX)] {
+            assert_eq!(surface.point_at(i, j), None);
+            assert_eq!(surface.normal_at(i, j), None);
+        }
+    }
+
+    #[test]
+    fn test_relax() {
+        let mut surface = EnneperSurface::new(20);
+        let border = *surface.point_at(0, 7).unwrap();
+        surface.points[9][11].z += 3.0;
+        surface.points[4][5].x -= 1.5;
+        surface.refresh_curvature_field();
+
+        let perturbed = surface.total_tension();
+        let mut tension = perturbed;
+        for _ in 0..25 {
+            surface.relax(1, 0.8);
+            let relaxed = surface.total_tension();
+            assert!(relaxed <= tension + 1e-12, "{} > {}", relaxed, tension);
+            tension = relaxed;
+        }
+        assert!(tension < 0.5 * perturbed);
+        assert_eq!(surface.point_at(0, 7), Some(&border));
+        assert!(surface.normal_at(9, 11).unwrap().is_finite());
+
+        // A zero rate leaves the surface alone
+        let before = surface.points.clone();
+        surface.relax(3, 0.0);
+        assert_eq!(surface.points, before);
+    }
+
+    #[test]
+    fn test_gaussian_curvature() {
+        let surface = EnneperSurface::new(40);
+        let res = surface.resolution;
+
+        for i in 1..res - 1 {
+            for j in 1..res - 1 {
+                assert!(surface.gaussian_curvature(i, j) < 0.0, "K >= 0 at ({}, {})", i, j);
+            }
+        }
+
+        // Analytic value: K = -4 / (1 + u² + v²)⁴
+        let (i, j) = (24, 18);
+        let (u, v) = (surface.parameter(i), surface.parameter(j));
+        let analytic = -4.0 / (1.0 + u * u + v * v).powi(4);
+        let discrete = surface.gaussian_curvature(i, j);
+        assert!(
+            (discrete - analytic).abs() < 0.05 * analytic.abs(),
+            "{} vs {}",
+            discrete,
+            analytic
+        );
+
+        assert_eq!(surface.gaussian_curvature(0, 5), 0.0);
+        assert_eq!(surface.gaussian_curvature(res, res), 0.0);
+    }
+
+    #[test]
+    fn test_cached_curvature_field() {
+        let mut surface = EnneperSurface::new(24);
+        let res = surface.resolution;
+        assert_eq!(surface.curvature_field().len(), res * res);
+        for i in 0..res {
+            for j in 0..res {
+                assert_eq!(surface.discrete_tension(i, j), surface.laplacian_magnitude(i, j));
+                #[allow(deprecated)]
+                let old_name = surface.mean_curvature(i, j);
+                assert_eq!(old_name, surface.discrete_tension(i, j));
+            }
+        }
+
+        // Direct edits go stale until refreshed; regenerate restores the surface
+        let before = surface.total_tension();
+        surface.points[5][5].z += 1.0;
+        assert_eq!(surface.total_tension(), before);
+        surface.refresh_curvature_field();
+        assert_ne!(surface.total_tension(), before);
+        assert_eq!(surface.discrete_tension(5, 5), surface.laplacian_magnitude(5, 5));
+        surface.regenerate();
+        assert_eq!(surface.points.len(), res);
+        assert_eq!(surface.total_tension(), before);
+    }
+
+    #[test]
+    fn test_cached_curvature_large_grid() {
+        use std::time::Instant;
+
+        let surface = EnneperSurface::new(256);
+
+        let start = Instant::now();
+        let cached: f64 = (0..100).map(|_| surface.total_tension()).sum::<f64>() / 100.0;
+        let cached_time = start.elapsed();
+
+        let start = Instant::now();
+        let mut on_demand = 0.0;
+        for i in 1..255 {
+            for j in 1..255 {
+                on_demand += surface.laplacian_magnitude(i, j);
+            }
+        }
+        on_demand /= (254 * 254) as f64;
+        let on_demand_time = start.elapsed();
+
+        println!("256x256: 100 cached={:?}, 1 on-demand={:?}", cached_time, on_demand_time);
+        assert!((cached - on_demand).abs() < 1e-9 * on_demand.max(1.0));
+    }
+
+    #[test]
+    fn test_confidence() {
+        let crystal = TruthState::Crystal { curvature: 0.0, tension: TENSION_THRESHOLD * 0.2 };
+        let annealing = TruthState::Annealing { curvature: 0.0, tension: TENSION_THRESHOLD * 2.0 };
+        let hallucination =
+            TruthState::Hallucination { curvature: 1.0, tension: TENSION_THRESHOLD * 5.0 };
+
+        assert!(crystal.confidence() > 90.0);
+        assert!(crystal.confidence() > annealing.confidence() + 50.0);
+        assert!(annealing.confidence() > hallucination.confidence());
+        assert!(hallucination.confidence() < 10.0);
+
+        // No cliffs: classify either side of each boundary and compare
+        for &(tension, curvature) in &[
+            (TENSION_THRESHOLD, 0.0),
+            (TENSION_THRESHOLD * 3.0, 0.0),
+            (TENSION_THRESHOLD * 0.5, CURVATURE_THRESHOLD),
+        ] {
+            let below = classify(tension - 1e-9, curvature - 1e-9, DEFAULT_THRESHOLDS);
+            let above = classify(tension + 1e-9, curvature + 1e-9, DEFAULT_THRESHOLDS);
+            assert_ne!(below.name(), above.name());
+            assert!((below.confidence() - above.confidence()).abs() < 1e-3);
+        }
+
+        let state = NarrativeGeometry::new(50).map_narrative("Energy is conserved");
+        assert!(state.confidence() > 0.0 && state.confidence() <= 100.0);
+        assert_eq!(classify(f64::NAN, 0.0, DEFAULT_THRESHOLDS).confidence(), 0.0);
+
+        // Scored against the active thresholds, confidence follows the verdict
+        let loose = (TENSION_THRESHOLD * 5.0, CURVATURE_THRESHOLD);
+        let mut geometry = NarrativeGeometry::new(50);
+        geometry.set_thresholds(loose);
+        let crystal = classify(TENSION_THRESHOLD * 4.0, 0.0, loose);
+        assert!(crystal.is_valid());
+        assert!(geometry.confidence(&crystal) > 50.0);
+        assert!(crystal.confidence() < 10.0);
+
+        let validator =
+            UnifiedFieldValidator::builder().tension_threshold(TENSION_THRESHOLD * 5.0).build();
+        assert_eq!(validator.confidence(&crystal), geometry.confidence(&crystal));
+    }
+
+    #[test]
+    fn test_validate_annotated() {
+        let text = "The cat sat on the mat with paradigm-shifting hyper-synergistic disruption";
+        let mut validator = UnifiedFieldValidator::new();
+        let (state, annotations) = validator.validate_annotated(text);
+
+        assert_eq!(annotations.len(), text.split_whitespace().count());
+        assert_eq!(state, UnifiedFieldValidator::new().validate(text));
+
+        let loudest = annotations
+            .iter()
+            .max_by(|a, b| a.tension.total_cmp(&b.tension))
+            .unwrap();
+        assert!(
+            ["paradigm-shifting", "hyper-synergistic"].contains(&loudest.word.as_str()),
+            "loudest word {}",
+            loudest.word
+        );
+
+        let (_, empty) = validator.validate_annotated("");
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_tension_delta() {
+        let mut geometry = NarrativeGeometry::new(50);
+        let clean = "Water boils at one hundred degrees at sea level";
+        let hyped = "Water synergistically boils at paradigm-shifting one hundred \
+                     degrees, leveraging holistic sea-level disruption";
+
+        let delta = geometry.tension_delta(clean, hyped);
+        assert!(delta > 0.0, "delta {}", delta);
+        assert_eq!(geometry.tension_delta(hyped, clean), -delta);
+        assert_eq!(geometry.tension_delta(clean, clean), 0.0);
+    }
+
+    #[test]
+    fn test_shared_surface() {
+        let narratives = [
+            "The speed of light in vacuum is constant",
+            "leverage synergy leverage synergy leverage synergy",
+            "Water boils at one hundred degrees at sea level",
+        ];
+
+        let surface = Arc::new(EnneperSurface::new(64));
+        let mut first = NarrativeGeometry::with_surface(Arc::clone(&surface));
+        let mut second = NarrativeGeometry::with_surface(Arc::clone(&surface));
+        assert!(Arc::ptr_eq(first.surface(), second.surface()));
+
+        for text in &narratives {
+            let expected = NarrativeGeometry::new(64).map_narrative(text);
+            assert_eq!(first.map_narrative(text), expected);
+            assert_eq!(second.map_narrative(text), expected);
+        }
+        assert_eq!(Arc::strong_count(&surface), 3);
+    }
+
+    #[test]
+    fn test_narrative_path() {
+        let geometry = NarrativeGeometry::new(20);
+        let text = "The speed of light in vacuum is constant";
+        let path = geometry.narrative_path(text);
+
+        assert_eq!(path.len(), text.split_whitespace().count());
+        for point in &path {
+            assert!(geometry.surface.points.iter().flatten().any(|p| p == point));
+        }
+        assert_eq!(path, geometry.narrative_path(text));
+        assert!(geometry.narrative_path("   ").is_empty());
+    }
+
+    #[test]
+    fn test_plain_word_tension() {
+        // Plain words up to four letters add nothing; the excess counts
+        assert_eq!(NarrativeGeometry::narrative_tension("a"), 0.0);
+        assert_eq!(NarrativeGeometry::narrative_tension("blue"), 0.0);
+        assert!((NarrativeGeometry::narrative_tension("mammals") - 0.3).abs() < 1e-12);
+        // Symbols still count: six characters plus two symbols
+        assert!((NarrativeGeometry::narrative_tension("blue!!") - 0.4).abs() < 1e-12);
+
+        // Short factual statements can crystallize; buzzword salad cannot
+        let mut geometry = NarrativeGeometry::new(32);
+        assert!(geometry.map_narrative("The sky is blue").is_valid());
+        assert!(matches!(
+            geometry.map_narrative("Synergistic paradigm-shifting hyper-scalable disruption!!!"),
+            TruthState::Hallucination { .. }
+        ));
+    }
+
+    #[test]
+    fn test_batch_placement_pinned() {
+        // u steps through the interior rows in word order and wraps after
+        // resolution - 2 words; v is the FNV-1a word hash mod resolution
+        let geometry = NarrativeGeometry::new(8);
+        let text = "Water boils at one hundred degrees at sea level";
+        let cells: Vec<(usize, usize)> = text
+            .split_whitespace()
+            .enumerate()
+            .map(|(idx, word)| geometry.word_cell(idx, word))
+            .collect();
+        let pinned = [(1, 0), (2, 0), (3, 0), (4, 7), (5, 3), (6, 6), (1, 0), (2, 4), (3, 5)];
+        assert_eq!(cells, pinned);
+        assert_eq!(
+            geometry.narrative_path(text),
+            pinned.iter().map(|&(i, j)| geometry.surface.points[i][j]).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_unique_word_count() {
+        assert_eq!(unique_word_count(&[]), 0);
+        assert_eq!(unique_word_count(&["truth", "lie", "truth", "truth"]), 2);
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[test]
+    fn test_libm_matches_std() {
+        for &x in &[-2.5_f64, -0.3, 0.0, 0.7, 1.0, 3.9] {
+            assert!((FloatMath::sqrt(x.abs()) - x.abs().sqrt()).abs() < 1e-12);
+            assert!((FloatMath::sin(x) - x.sin()).abs() < 1e-12);
+            assert!((FloatMath::cos(x) - x.cos()).abs() < 1e-12);
+            assert!((FloatMath::cosh(x) - x.cosh()).abs() < 1e-12);
+            assert!((FloatMath::tanh(x) - x.tanh()).abs() < 1e-12);
+            assert!((FloatMath::powi(x, 3) - x.powi(3)).abs() < 1e-12);
+            assert_eq!(FloatMath::floor(x), x.floor());
+            assert_eq!(FloatMath::ceil(x), x.ceil());
+        }
+    }
+
+    #[test]
+    fn test_flux_history() {
+        let mut stable = EntangledFluxValidator::new();
+        for _ in 0..10 {
+            stable.advance(0.1);
+        }
+        assert_eq!(stable.history().len(), 10);
+        assert_eq!(stable.first_instability(), None);
+        assert!(stable.is_stable());
+
+        // At step 0.1 the derivative first exceeds the bound on step 21
+        let mut diverging = EntangledFluxValidator::new();
+        let mut first_unstable = None;
+        for i in 0..30 {
+            diverging.advance(0.1);
+            if first_unstable.is_none() && !diverging.is_stable() {
+                first_unstable = Some(i);
+            }
+        }
+        assert_eq!(diverging.first_instability(), Some(21));
+        assert_eq!(first_unstable, Some(21));
+        assert!(diverging.history()[21].1.abs() >= 10.0);
+    }
+
+    #[test]
+    fn test_mobius_flip_edge() {
+        let mut torus = MobiusTorus::new(1);
+        let steps = (2.0 * PI / 0.1).ceil() as usize;
+
+        let edges = (0..steps).filter(|_| torus.advance(0.1).1).count();
+        assert_eq!(edges, 1);
+        assert!(torus.has_flipped());
+
+        torus.reset();
+        assert!(!torus.has_flipped());
+
+        // Zero twists never flip
+        let mut flat = MobiusTorus::new(0);
+        assert_eq!((0..steps).filter(|_| flat.advance(0.1).1).count(), 0);
+        assert!(!flat.has_flipped());
+    }
+
+    #[test]
+    fn test_mobius_twist_count() {
+        let steps = (2.0 * PI / 0.1).ceil() as usize;
+        let edges = |half_twists| {
+            let mut torus = MobiusTorus::new(half_twists);
+            let edges = (0..steps).filter(|_| torus.advance(0.1).1).count();
+            (edges, torus.is_inverted())
+        };
+
+        assert_eq!(edges(1), (1, true));
+        assert_eq!(edges(3), (3, true));
+        // Even twists flip and flip back, ending the loop uninverted
+        assert_eq!(edges(2), (2, false));
+        assert_eq!(edges(4), (4, false));
+
+        // One large step across both edges of a 2-twist band cancels out
+        let mut torus = MobiusTorus::new(2);
+        assert!(!torus.advance(1.8 * PI).1);
+        assert!(torus.has_flipped() && !torus.is_inverted());
+    }
+
+    #[test]
+    fn test_mobius_trajectory() {
+        let mut torus = MobiusTorus::new(1);
+        torus.advance(0.1);
+        assert!(torus.trajectory().is_empty());
+
+        torus.reset();
+        torus.record = true;
+        let steps = (2.0 * PI / 0.1).ceil() as usize;
+        let results: Vec<_> = (0..steps).map(|_| torus.advance(0.1)).collect();
+
+        assert_eq!(torus.trajectory(), &results[..]);
+        assert_eq!(torus.trajectory().iter().filter(|(_, flipped)| *flipped).count(), 1);
+
+        torus.clear_trajectory();
+        assert!(torus.trajectory().is_empty());
+    }
+
+    #[test]
+    fn test_mobius_geometry() {
+        let mut tight = MobiusTorus::new(1);
+        let mut loose = MobiusTorus::with_geometry(1, 5.0, 1.0, PHI);
+
+        for _ in 0..40 {
+            let (near, near_flip) = tight.advance(0.1);
+            let (far, far_flip) = loose.advance(0.1);
+            assert_eq!(tight.theta, loose.theta);
+            assert!(far.magnitude() > near.magnitude());
+            assert_eq!(near_flip, far_flip);
+        }
+
+        let mut slow = MobiusTorus::with_geometry(1, 2.0, 1.0, 0.5);
+        slow.advance(0.2);
+        assert!((slow.phi - 0.1).abs() < 1e-12);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_point3d_round_trip() {
+        let p = Point3D::new(1.0, -2.5, 3.25);
+        let json = serde_json::to_string(&p).unwrap();
+        let back: Point3D = serde_json::from_str(&json).unwrap();
+        assert_eq!(p, back);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_truth_state_round_trip() {
+        let state = TruthState::Crystal {
+            curvature: 0.01,
+            tension: 0.05,
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(json, r#"{"state":"Crystal","curvature":0.01,"tension":0.05}"#);
+        let back: TruthState = serde_json::from_str(&json).unwrap();
+        assert_eq!(state, back);
+
+        // NaN input classifies as Hallucination at infinite tension
+        let unbounded = classify(f64::NAN, f64::NAN, DEFAULT_THRESHOLDS);
+        let json = serde_json::to_string(&unbounded).unwrap();
+        assert_eq!(
+            json,
+            r#"{"state":"Hallucination","curvature":"Infinity","tension":"Infinity"}"#
+        );
+        let back: TruthState = serde_json::from_str(&json).unwrap();
+        assert_eq!(unbounded, back);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_pseudosphere_analysis_round_trip() {
+        let analysis = GeometricLieDetector::new().analyze("the the the the the truth");
+        let json = serde_json::to_string(&analysis).unwrap();
+        let back: PseudosphereAnalysis = serde_json::from_str(&json).unwrap();
+        assert_eq!(analysis, back);
+
+        let curvature = CurvatureType::Hyperbolic;
+        let json = serde_json::to_string(&curvature).unwrap();
+        assert_eq!(serde_json::from_str::<CurvatureType>(&json).unwrap(), curvature);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_implicit_derivative_infinite_slope() {
+        let deriv = ImplicitDerivative {
+            slope: f64::INFINITY,
+            is_bounded: false,
+            is_tear: true,
+            df_dx: 1.0,
+            df_dy: 0.0,
+        };
+        let json = serde_json::to_string(&deriv).unwrap();
+        assert!(json.contains(r#""slope":"Infinity""#));
+        let back: ImplicitDerivative = serde_json::from_str(&json).unwrap();
+        assert_eq!(deriv, back);
+    }
+
+    #[test]
+    fn test_validate_long_narrative_flips() {
+        // Zero resonance tolerance keeps Poincaré locked, isolating the flip.
+        // A loose tension threshold lets one-letter words (raw tension 0.1)
+        // crystallize whatever the word scoring baseline
+        let validator = || {
+            UnifiedFieldValidator::builder()
+                .resonance_tolerance(0.0)
+                .tension_threshold(TENSION_THRESHOLD * 5.0)
+                .build()
+        };
+
+        // Ten words stay short of π and come out Crystal
+        let short = ["a"; 10].join(" ");
+        assert!(validator().validate(&short).is_valid());
+
+        // 40 words advance the chronometer past π, triggering the flip
+        let text = ["a"; 40].join(" ");
+        let mut validator = validator();
+        assert!(validator.geometry.map_narrative(&text).is_valid());
+
+        let state = validator.validate(&text);
+        assert!(validator.chronometer.theta > PI);
+        assert!(
+            matches!(state, TruthState::Annealing { .. }),
+            "Flipped narrative must be downgraded to Annealing"
+        );
+    }
+
+    #[test]
+    fn test_poincare_nearest_resonance() {
+        let mut poincare = PoincareSectionValidator::new();
+
+        // Irrational offset: locked, no resonance
+        poincare.golden_phase = 1.0;
+        poincare.silver_phase = 0.0;
+        assert!(poincare.is_locked());
+        assert_eq!(poincare.nearest_resonance(), None);
+
+        // Sitting on 3π/5: resonance n = 3
+        poincare.golden_phase = 3.0 * PI / 5.0 + 0.005;
+        assert!(!poincare.is_locked());
+        let (n, rational) = poincare.nearest_resonance().unwrap();
+        assert_eq!(n, 3);
+        assert!((rational - 3.0 * PI / 5.0).abs() < 1e-12);
+        assert!((poincare.phase_diff() - rational).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_poincare_lenient() {
+        // The first step lands the phase difference exactly on π/5
+        let blip = (PI / 5.0) / (DELTA - PHI);
+        let steps = [blip, 0.3, 0.3];
+
+        let mut strict = PoincareSectionValidator::new();
+        assert!(!strict.validate_sequence(&steps));
+        let mut strict = PoincareSectionValidator::new();
+        assert!(!strict.validate_sequence_lenient(&steps, 0));
+
+        let mut lenient = PoincareSectionValidator::new();
+        assert!(lenient.validate_sequence_lenient(&steps, 1));
+        assert_eq!(lenient.unlocked_steps(), 1);
+
+        lenient.reset();
+        assert_eq!(lenient.unlocked_steps(), 0);
+    }
+
+    #[test]
+    fn test_analyze_windows() {
+        let dense = "Maxwell unified electricity magnetism light predicting waves \
+                     travelling at constant speed through empty space";
+        let text = format!("{} {}", dense, vec!["very"; 16].join(" "));
+        let detector = GeometricLieDetector::new();
+
+        let windows = detector.analyze_windows(&text, 8);
+        assert_eq!(windows.len(), 4);
+        assert_eq!(windows[0].curvature_type, CurvatureType::Spherical);
+        assert_eq!(windows[3].curvature_type, CurvatureType::Hyperbolic);
+
+        // Overlapping windows trace the transition word by word
+        let sliding = detector.analyze_windows_with_step(&text, 8, 4);
+        assert_eq!(sliding.len(), 7);
+        assert!(sliding
+            .windows(2)
+            .any(|pair| pair[0].curvature_type != pair[1].curvature_type));
+
+        assert!(detector.analyze_windows("", 8).is_empty());
+        assert_eq!(detector.analyze_windows("one two", 8).len(), 1);
+    }
+
+    #[test]
+    fn test_tension_weighting() {
+        let mut geometry = NarrativeGeometry::new(32);
+        assert_eq!(geometry.weighting(), TensionWeighting::Uniform);
+        let middle = "a b c d Incomprehensibilities e f g h";
+        let end = "a b c d e f g h Incomprehensibilities";
+        let uniform = geometry.map_narrative(end).tension();
+
+        geometry.set_weighting(TensionWeighting::Edges);
+        let mid_tension = geometry.map_narrative(middle).tension();
+        let end_tension = geometry.map_narrative(end).tension();
+        assert!(end_tension > mid_tension);
+        assert!(end_tension > uniform);
+
+        for weighting in [TensionWeighting::Primacy, TensionWeighting::Recency] {
+            let mean: f64 = (0..9).map(|idx| weighting.weight(idx, 9)).sum::<f64>() / 9.0;
+            assert!((mean - 1.0).abs() < 1e-12);
+        }
+        assert_eq!(TensionWeighting::Edges.weight(0, 1), 1.0);
+    }
+
+    #[test]
+    fn test_normalize_narrative() {
+        assert_eq!(
+            normalize_narrative("  \"Truth,\"  she said — DON'T  lie.\n"),
+            "truth she said don't lie"
+        );
+        assert_eq!(normalize_narrative(" ... "), "");
+
+        let variants = [
+            "The derivative of position is velocity",
+            "the derivative of position is velocity.",
+            "  THE derivative,  of position\tis (velocity)!  ",
+        ];
+        assert_eq!(normalize_narrative(variants[1]), normalize_narrative(variants[2]));
+
+        let mut validator = UnifiedFieldValidator::new();
+        validator.normalize = true;
+        let detector = GeometricLieDetector::with_config(DetectorConfig {
+            normalize: true,
+            ..DetectorConfig::default()
+        });
+        let expected = (
+            validator.validate_isolated(variants[0]),
+            detector.analyze(variants[0]),
+            validator.fingerprint(variants[0]),
+        );
+        for text in &variants[1..] {
+            assert_eq!(validator.validate_isolated(text), expected.0);
+            assert_eq!(detector.analyze(text), expected.1);
+            assert_eq!(validator.fingerprint(text), expected.2);
+        }
+
+        // Off by default: punctuation still counts
+        assert_ne!(
+            GeometricLieDetector::new().analyze(variants[2]),
+            GeometricLieDetector::new().analyze(variants[0])
+        );
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let mut validator = UnifiedFieldValidator::new();
+        let text = "The derivative of position is velocity";
+        let id = validator.fingerprint(text);
+
+        // Stable across calls and whitespace
+        assert_eq!(validator.fingerprint(text), id);
+        assert_eq!(
+            validator.fingerprint("  The derivative\tof position\n\nis   velocity "),
+            id
+        );
+        assert_eq!(UnifiedFieldValidator::new().fingerprint(text), id);
+
+        // Any changed word diverges
+        assert_ne!(validator.fingerprint("The derivative of position is speed"), id);
+        assert_ne!(validator.fingerprint("The derivative of position is  velocity."), id);
+    }
+
+    #[test]
+    fn test_analysis_delta() {
+        let detector = GeometricLieDetector::new();
+        let original = "Maxwell unified electricity magnetism and light";
+        let padded = format!("{} {}", original, ["basically"; 6].join(" "));
+
+        let before = detector.analyze(original);
+        let after = detector.analyze(&padded);
+        let delta = before.delta(&after);
+        assert!(delta.horn_ratio_change > 0.0);
+        assert!(delta.curvature_change < 0.0);
+        assert!(delta.got_worse());
+
+        // Identical analyses are neither better nor worse
+        let same = before.delta(&before);
+        assert!(!same.curvature_type_changed());
+        assert!(!same.got_worse());
+
+        // Removing the filler is an improvement
+        assert!(!after.delta(&before).got_worse());
+    }
+
+    #[test]
+    fn test_poincare_tolerance() {
+        // Phase difference 0.03 away from 2π/5
+        let diff = 2.0 * PI / 5.0 + 0.03;
+
+        let mut lenient = PoincareSectionValidator::with_tolerance(0.01, 10);
+        lenient.golden_phase = diff;
+        assert!(lenient.is_locked());
+
+        let mut strict = PoincareSectionValidator::with_tolerance(0.05, 10);
+        strict.golden_phase = diff;
+        assert!(!strict.is_locked());
+        assert_eq!(strict.nearest_resonance().map(|(n, _)| n), Some(2));
+    }
+
+    #[test]
+    fn test_enneper_range() {
+        let narrow = EnneperSurface::with_range(16, 1.0);
+        let wide = EnneperSurface::with_range(16, 3.0);
+
+        assert_eq!(narrow.range, 1.0);
+        assert!((narrow.parameter(0) + 1.0).abs() < 1e-12);
+
+        // Corners of the wider window sit farther out
+        for &(i, j) in &[(0, 0), (0, 15), (15, 0), (15, 15)] {
+            assert!(wide.points[i][j].magnitude() > narrow.points[i][j].magnitude());
+        }
+    }
+
+    #[test]
+    fn test_obj_export() {
+        let surface = EnneperSurface::new(8);
+        let obj = surface.to_obj();
+
+        let vertices = obj.lines().filter(|l| l.starts_with("v ")).count();
+        let faces = obj.lines().filter(|l| l.starts_with("f ")).count();
+        assert_eq!(vertices, 8 * 8);
+        assert_eq!(faces, 7 * 7);
+        assert!(obj.lines().all(|l| l.starts_with('v') || l.starts_with('f')));
+
+        let pseudo = Pseudosphere::new(16);
+        let obj = pseudo.to_obj(4.0, 12);
+        assert_eq!(obj.lines().filter(|l| l.starts_with("v ")).count(), 16 * 12);
+        assert!(obj.lines().all(|l| l.starts_with("v ") || l.starts_with("f ")));
+
+        // Every face index is 1-based and in range
+        for line in obj.lines().filter(|l| l.starts_with("f ")) {
+            for idx in line[2..].split_whitespace() {
+                let idx: usize = idx.parse().unwrap();
+                assert!((1..=16 * 12).contains(&idx));
+            }
+        }
+    }
+
+    #[test]
+    fn test_narrative_hash() {
+        assert_ne!(narrative_hash("stakeholder", 0), narrative_hash("stakeholders", 0));
+
+        // Stable across runs (reference values, pinned in every module)
+        assert_eq!(narrative_hash("truth", 0), 0x8c4aa5a7274838be);
+        assert_eq!(Fnv1aHasher.hash_word("truth", 0), 0x8c4aa5a7274838be);
+        assert_eq!(word_hash("truth", 0), 0x690e781);
+        assert_eq!(Poly31Hasher.hash_word("truth", 0), 0x690e781);
+
+        // Bytes by default, chars with `unicode`; the same on ASCII
+        #[cfg(not(feature = "unicode"))]
+        assert_eq!(
+            narrative_hash("v\u{e9}rit\u{e9}", 3),
+            Fnv1aHasher.hash_word("v\u{e9}rit\u{e9}", 3)
+        );
+        #[cfg(feature = "unicode")]
+        assert_eq!(
+            narrative_hash("v\u{e9}rit\u{e9}", 3),
+            Fnv1aHasher.hash_chars("v\u{e9}rit\u{e9}", 3)
+        );
+        assert_eq!(Fnv1aHasher.hash_chars("truth", 9), Fnv1aHasher.hash_word("truth", 9));
+
+        // Seeds decorrelate
+        assert_ne!(narrative_hash("truth", 0), narrative_hash("truth", 42));
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_unicode_word_tension() {
+        // Composed, decomposed and ASCII spellings weigh the same
+        let ascii = NarrativeGeometry::word_tension("cafe");
+        assert_eq!(NarrativeGeometry::word_tension("caf\u{e9}"), ascii);
+        assert_eq!(NarrativeGeometry::word_tension("cafe\u{301}"), ascii);
+
+        // CJK: three characters, not nine bytes
+        assert!((NarrativeGeometry::word_tension("真実だ") - 0.3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_curvature_weighted_placement() {
+        let surface = Arc::new(EnneperSurface::new(24));
+        let flatness = |i: usize, j: usize| surface.gaussian_curvature(i, j).abs();
+
+        // Rows rank their interior cells flattest first
+        for i in 1..23 {
+            let cells = surface.flattest_cells(i);
+            assert_eq!(cells.len(), 22);
+            assert!(cells.windows(2).all(|w| flatness(i, w[0]) <= flatness(i, w[1])));
+        }
+        assert!(flatness(12, surface.flattest_cells(12)[0]) < flatness(12, 12) * 0.1);
+
+        let cell_curvature = |geometry: &NarrativeGeometry| -> f64 {
+            (0..12)
+                .map(|idx| {
+                    let (i, j) = geometry.word_cell(idx, "is");
+                    flatness(i, j.clamp(1, 22))
+                })
+                .sum()
+        };
+
+        let mut geometry = NarrativeGeometry::with_surface(Arc::clone(&surface));
+        assert_eq!(geometry.placement(), PlacementMode::Modulo);
+        let modulo = cell_curvature(&geometry);
+
+        geometry.set_placement(PlacementMode::CurvatureWeighted);
+        let weighted = cell_curvature(&geometry);
+        assert!(weighted < modulo, "{} >= {}", weighted, modulo);
+
+        // Long, symbol-heavy words are pushed toward the curved cells
+        let (i, plain) = geometry.word_cell(5, "is");
+        let (_, loaded) = geometry.word_cell(5, "hyper-synergistic-paradigm!!!");
+        assert!(flatness(i, loaded) > flatness(i, plain));
+    }
+
+    #[test]
+    fn test_calibrate() {
+        let truths = ["The sky is blue", "Paris is in France", "Cats are mammals", "Ice is cold"];
+        let lies = [
+            "Our synergistic paradigm-shifting blockchain revolutionizes everything!!!",
+            "Unprecedented, hyper-scalable, quantum-enabled disruption...",
+            "Trust me: guaranteed 1000% returns!!!",
+        ];
+        let accuracy = |geometry: &mut NarrativeGeometry| {
+            let right = truths.iter().filter(|t| geometry.map_narrative(t).is_valid()).count()
+                + lies.iter().filter(|l| !geometry.map_narrative(l).is_valid()).count();
+            right as f64 / (truths.len() + lies.len()) as f64
+        };
+
+        let mut geometry = NarrativeGeometry::new(32);
+        assert_eq!(geometry.thresholds(), (TENSION_THRESHOLD, CURVATURE_THRESHOLD));
+        let before = accuracy(&mut geometry);
+
+        let (tension, curvature) = geometry.calibrate(&truths, &lies);
+        assert_eq!(geometry.thresholds(), (tension, curvature));
+        assert!(tension > TENSION_THRESHOLD);
+        assert!(accuracy(&mut geometry) > before);
+        assert_eq!(accuracy(&mut geometry), 1.0);
+
+        // Streams inherit the calibrated thresholds
+        let mut stream = geometry.stream();
+        "Ice is cold".split_whitespace().for_each(|w| stream.push_word(w));
+        assert!(stream.current_state().is_valid());
+
+        // Nothing to learn from one-sided data
+        assert_eq!(geometry.calibrate(&truths, &[]), (tension, curvature));
+    }
+
+    #[test]
+    fn test_thread_safe_validator() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<ThreadSafeValidator>();
+
+        let shared = Arc::new(ThreadSafeValidator::new());
+        let claims = [
+            "The sky is blue today",
+            "synergy synergy synergy leverage",
+            "E equals m c squared",
+        ];
+        let expected = UnifiedFieldValidator::new().validate_batch(&claims);
+
+        // Each thread walks the claims from a different starting point
+        let handles: Vec<_> = (0..16)
+            .map(|t| {
+                let shared = Arc::clone(&shared);
+                std::thread::spawn(move || {
+                    (0..30)
+                        .map(|k| (t + k) % claims.len())
+                        .map(|i| (i, shared.validate(claims[i])))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            for (i, state) in handle.join().unwrap() {
+                assert_eq!(state, expected[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_validator_builder() {
+        let validator = UnifiedFieldValidator::builder()
+            .resolution(48)
+            .half_twists(3)
+            .tension_threshold(0.2)
+            .curvature_threshold(0.08)
+            .resonance_tolerance(0.05)
+            .normalize(true)
+            .build();
+        assert_eq!(validator.geometry.surface().resolution, 48);
+        assert_eq!(validator.geometry.surface().points.len(), 48);
+        assert_eq!(validator.geometry.thresholds(), (0.2, 0.08));
+        assert_eq!(validator.chronometer.half_twists, 3);
+        assert_eq!(validator.poincare.resonance_tolerance, 0.05);
+        assert!(validator.normalize);
+
+        // The default build is `new`
+        let built = UnifiedFieldValidatorBuilder::new().build();
+        let new = UnifiedFieldValidator::new();
+        assert_eq!(built.snapshot(), new.snapshot());
+        assert_eq!(built.geometry.thresholds(), DEFAULT_THRESHOLDS);
+        assert_eq!(built.chronometer.half_twists, 1);
+        assert_eq!(built.poincare.resonance_tolerance, new.poincare.resonance_tolerance);
+    }
+
+    #[test]
+    fn test_snapshot_restore() {
+        let mut live = UnifiedFieldValidator::new();
+        live.validate("Energy is conserved in a closed system");
+        live.chronometer.compress(0.5);
+
+        let mut restored = UnifiedFieldValidator::restore(live.snapshot());
+        assert_eq!(restored.snapshot(), live.snapshot());
+
+        for text in ["The sky is blue", &["word"; 40].join(" "), "Ice is cold"] {
+            assert_eq!(restored.validate(text), live.validate(text));
+            assert_eq!(restored.snapshot(), live.snapshot());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_validator_state_round_trip() {
+        // Dyadic values survive JSON exactly
+        let state = ValidatorState {
+            golden_phase: 1.5,
+            silver_phase: 0.25,
+            unlocked_steps: 2,
+            theta: 3.0,
+            phi: 0.5,
+            compression: 0.75,
+            flipped: true,
+            inverted: true,
+            resolution: 16,
+            range: 2.0,
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        let back: ValidatorState = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, state);
+        assert_eq!(UnifiedFieldValidator::restore(back).snapshot(), state);
+    }
+
+    #[test]
+    fn test_validate_batch_independent() {
+        let mut validator = UnifiedFieldValidator::new();
+        let long = vec!["word"; 50].join(" ");
+        let claim = "The sky is blue today";
+
+        let results = validator.validate_batch(&[claim, &long, claim]);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0], results[2]);
+
+        let alone = UnifiedFieldValidator::new().validate_batch(&[claim]);
+        assert_eq!(results[0], alone[0]);
+
+        // Isolated validation does not advance the shared state
+        let theta = validator.chronometer.theta;
+        validator.validate_isolated(&long);
+        assert_eq!(validator.chronometer.theta, theta);
+    }
+
+    #[test]
+    fn test_lie_reasons() {
+        let detector = GeometricLieDetector::new();
+
+        // Repetitive text curves hyperbolically
+        let repetitive = detector.analyze("buy buy buy buy buy buy now now now now");
+        assert!(repetitive.reasons().contains(&LieReason::HyperbolicCurvature));
+        assert!(repetitive.is_lie_geometry);
+
+        // Verbose but thin: one long word, lots of surface for no volume
+        let thin = detector.analyze("antidisestablishmentarianism");
+        assert_eq!(thin.reasons(), vec![LieReason::ExcessiveHornRatio(28.0)]);
+        assert!(thin.is_lie_geometry);
+
+        let clean = detector.analyze("Water boils at one hundred degrees");
+        assert!(clean.reasons().is_empty());
+        assert!(!clean.is_lie_geometry);
+    }
+
+    #[test]
+    fn test_detector_config() {
+        let sample = "the party shall pay the party the party the party";
+
+        let strict = GeometricLieDetector::new().analyze(sample);
+        assert_eq!(strict.curvature_type, CurvatureType::Hyperbolic);
+
+        let lenient = GeometricLieDetector::with_config(DetectorConfig {
+            flat_uniqueness: 0.2,
+            ..DetectorConfig::default()
+        });
+        let analysis = lenient.analyze(sample);
+        assert_eq!(analysis.curvature_type, CurvatureType::Flat);
+        assert!(!analysis.is_lie_geometry);
+    }
+
+    #[test]
+    fn test_tractrix_inverse() {
+        let tractrix = Tractrix::new(64);
+        for &t in &[0.01, 0.3, 1.0, 2.5, 7.0, 30.0] {
+            let (x, _) = tractrix.point(t);
+            let recovered = tractrix.t_for_x(x).unwrap();
+            assert!((recovered - t).abs() < 1e-6 * t.max(1.0), "t={} got {}", t, recovered);
+        }
+
+        assert_eq!(tractrix.t_for_x(0.0), Some(0.0));
+        assert_eq!(tractrix.t_for_x(-0.5), None);
+        assert_eq!(tractrix.t_for_x(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_tractrix_arc_length() {
+        let tractrix = Tractrix::new(256);
+
+        // ∫ sech·tanh = sech(t0) - sech(t1) → sech(0.01) as t1 grows
+        let expected = 1.0 / 0.01f64.cosh();
+        let length = tractrix.arc_length(0.01, 30.0);
+        assert!((length - expected).abs() < 1e-3, "arc length {}", length);
+        assert_eq!(tractrix.arc_length(2.0, 1.0), 0.0);
+
+        let pseudo = Pseudosphere::new(256);
+        let mut previous = 0.0;
+        for &t_max in &[0.5, 1.0, 2.0, 4.0, 8.0] {
+            let area = pseudo.surface_area(t_max);
+            assert!(area > previous, "area must grow with t_max");
+            previous = area;
+        }
+    }
+
+    #[test]
+    fn test_horn_ratio_shares_truncation() {
+        let pseudo = Pseudosphere::new(512);
+
+        // One horn converges to half the analytic volume
+        assert!((pseudo.volume_to(20.0) - pseudo.volume() / 2.0).abs() < 1e-3);
+
+        // Past the neck, area and volume both saturate, so the ratio settles
+        let r8 = pseudo.gabriels_horn_ratio(8.0);
+        let r16 = pseudo.gabriels_horn_ratio(16.0);
+        assert!(r8.is_finite());
+        assert!((r8 - r16).abs() / r16 < 0.01, "{} vs {}", r8, r16);
+    }
+
+    #[test]
+    fn test_horn_scales_with_radius() {
+        let unit = Pseudosphere::new(512);
+        let mut wide = Pseudosphere::new(512);
+        wide.radius = 2.0;
+
+        // Area grows as r², volume as r³, so the ratio falls as 1/r
+        assert!((wide.surface_area(8.0) - 4.0 * unit.surface_area(8.0)).abs() < 1e-9);
+        assert!((wide.volume_to(20.0) - wide.volume() / 2.0).abs() < 1e-2);
+        let ratio = wide.gabriels_horn_ratio(8.0) * 2.0;
+        assert!((ratio - unit.gabriels_horn_ratio(8.0)).abs() < 1e-9, "{}", ratio);
+    }
+
+    #[test]
+    fn test_narrative_stream_matches_batch() {
+        let mut geom = NarrativeGeometry::new(32);
+        let sentence = "Water boils at one hundred degrees at sea level";
+
+        let batch = geom.map_narrative(sentence);
+
+        let mut stream = geom.stream();
+        for word in sentence.split_whitespace() {
+            stream.push_word(word);
+        }
+        let streamed = stream.current_state();
+
+        assert_eq!(stream.tension_field().len(), 9);
+        assert_eq!(
+            std::mem::discriminant(&batch),
+            std::mem::discriminant(&streamed)
+        );
+        assert!((batch.tension() - streamed.tension()).abs() < 1e-9);
+
+        // Enneper's discrete Laplacian vanishes, so bump the surface to give
+        // every word a nonzero local curvature
+        let mut surface = EnneperSurface::new(32);
+        for i in 1..31 {
+            for j in 1..31 {
+                let bump = (narrative_hash(&format!("{},{}", i, j), 7) % 100) as f64 / 100.0;
+                surface.points[i][j].z += bump;
+            }
+        }
+        surface.refresh_curvature_field();
+        let mut geom = NarrativeGeometry::with_surface(Arc::new(surface));
+
+        for placement in [PlacementMode::Modulo, PlacementMode::CurvatureWeighted] {
+            geom.set_placement(placement);
+            let batch = geom.map_narrative(sentence);
+            let mut stream = geom.stream();
+            sentence.split_whitespace().for_each(|w| stream.push_word(w));
+
+            assert!(geom.tension_field().iter().all(|&t| t > 0.01), "{:?}", placement);
+            assert_eq!(stream.tension_field(), geom.tension_field());
+            assert_eq!(stream.current_state(), batch);
+        }
+    }
 }
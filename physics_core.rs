@@ -8,8 +8,59 @@
 //! - Crystallization Engine
 //!
 //! "Truth is the collapsed state of a Polycystic Waveform."
+//!
+//! Complex and SemanticWave math builds without `std` (only `alloc`); the
+//! native demo `main` needs the `std` feature. `check_no_std.sh` (run in CI)
+//! checks the no_std build.
+
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use core::f64::consts::PI;
+use core::ops::{Add, Mul, Sub};
+
+// Word hashing and anchor words shared with geometry_core and wasm_forge
+#[path = "tent_hash.rs"]
+mod tent_hash;
+
+pub use tent_hash::{
+    word_hash, DefaultTentHasher, Fnv1aHasher, Poly31Hasher, TentHasher, ANCHOR_WORDS,
+};
+
+/// Golden Ratio - phase spreading for word hashes
+const PHI: f64 = 1.618033988749895;
+
+/// Carrier frequency shared by all word waves
+const BASE_FREQUENCY: f64 = 1.0;
+
+/// libm-backed float methods for no_std; inherent f64 methods win under std
+#[cfg(not(feature = "std"))]
+#[allow(dead_code)]
+trait FloatMath {
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn ln(self) -> Self;
+    fn log2(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+}
 
-use std::f64::consts::PI;
+#[cfg(not(feature = "std"))]
+impl FloatMath for f64 {
+    fn sqrt(self) -> f64 { libm::sqrt(self) }
+    fn sin(self) -> f64 { libm::sin(self) }
+    fn cos(self) -> f64 { libm::cos(self) }
+    fn atan2(self, other: f64) -> f64 { libm::atan2(self, other) }
+    fn ln(self) -> f64 { libm::log(self) }
+    fn log2(self) -> f64 { libm::log2(self) }
+    fn powi(self, n: i32) -> f64 { libm::pow(self, n as f64) }
+}
 
 /// Complex number for wave calculations
 #[derive(Clone, Copy, Debug)]
@@ -37,6 +88,46 @@ impl Complex {
     pub fn add(&self, other: &Complex) -> Complex {
         Complex { re: self.re + other.re, im: self.im + other.im }
     }
+    
+    pub fn sub(&self, other: &Complex) -> Complex {
+        Complex { re: self.re - other.re, im: self.im - other.im }
+    }
+    
+    /// (a + bi)(c + di) = (ac - bd) + (ad + bc)i
+    pub fn mul(&self, other: &Complex) -> Complex {
+        Complex {
+            re: self.re * other.re - self.im * other.im,
+            im: self.re * other.im + self.im * other.re,
+        }
+    }
+    
+    pub fn scale(&self, factor: f64) -> Complex {
+        Complex { re: self.re * factor, im: self.im * factor }
+    }
+    
+    pub fn conj(&self) -> Complex {
+        Complex { re: self.re, im: -self.im }
+    }
+    
+    /// Argument in (-π, π]
+    pub fn phase(&self) -> f64 {
+        self.im.atan2(self.re)
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+    fn add(self, other: Complex) -> Complex { Complex::add(&self, &other) }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+    fn sub(self, other: Complex) -> Complex { Complex::sub(&self, &other) }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+    fn mul(self, other: Complex) -> Complex { Complex::mul(&self, &other) }
 }
 
 /// Semantic Waveform - represents a token/concept as a wave
@@ -58,6 +149,120 @@ impl SemanticWave {
     pub fn sample(&self, t: f64) -> Complex {
         Complex::from_polar(self.amplitude, self.frequency * t + self.phase)
     }
+    
+    /// Map a word to a wave, as wasm_forge's Pigment does:
+    /// phase from the word hash spread by φ, amplitude from √length scaled by
+    /// how evenly its characters are distributed (normalized Shannon entropy)
+    pub fn from_word(word: &str) -> SemanticWave {
+        let mut chars: Vec<char> = word.chars().collect();
+        let n = chars.len();
+        
+        let evenness = if n > 1 {
+            // Sorted runs count each character without needing a HashMap
+            chars.sort_unstable();
+            let entropy: f64 = chars.chunk_by(|a, b| a == b)
+                .map(|run| { let p = run.len() as f64 / n as f64; -p * p.log2() })
+                .sum();
+            entropy / (n as f64).log2()
+        } else {
+            1.0
+        };
+        let amplitude = (n as f64).sqrt() * (0.5 + 0.5 * evenness);
+        
+        let phase = (word_hash(word, 0) as f64 * PHI) % (2.0 * PI);
+        SemanticWave::new(amplitude, phase, BASE_FREQUENCY)
+    }
+    
+    /// `from_word`, but carried on the frequency of the word's band
+    pub fn from_word_banded(word: &str, band_map: &WaveBandMap) -> SemanticWave {
+        let wave = SemanticWave::from_word(word);
+        SemanticWave { frequency: band_map.frequency(band_map.band(word)), ..wave }
+    }
+}
+
+/// POS-like word class used to pick a frequency band
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WordBand { Function, Content, Anchor }
+
+/// Maps word classes to distinct carrier frequencies, so words only stay
+/// coherent over time with words of the same class
+#[derive(Clone, Debug)]
+pub struct WaveBandMap {
+    /// Words this short (after trimming punctuation) count as function words
+    pub function_max_len: usize,
+    pub anchors: &'static [&'static str],
+    pub function_frequency: f64,
+    pub content_frequency: f64,
+    pub anchor_frequency: f64,
+}
+
+impl Default for WaveBandMap {
+    fn default() -> Self {
+        WaveBandMap {
+            function_max_len: 3,
+            anchors: ANCHOR_WORDS,
+            function_frequency: BASE_FREQUENCY / 2.0,
+            content_frequency: BASE_FREQUENCY,
+            anchor_frequency: BASE_FREQUENCY * PHI, // incommensurate with the others
+        }
+    }
+}
+
+impl WaveBandMap {
+    /// Anchors are matched case-insensitively; length is counted in chars
+    pub fn band(&self, word: &str) -> WordBand {
+        let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+        if self.anchors.iter().any(|a| a.eq_ignore_ascii_case(word)) {
+            WordBand::Anchor
+        } else if word.chars().count() <= self.function_max_len {
+            WordBand::Function
+        } else {
+            WordBand::Content
+        }
+    }
+    
+    pub fn frequency(&self, band: WordBand) -> f64 {
+        match band {
+            WordBand::Function => self.function_frequency,
+            WordBand::Content => self.content_frequency,
+            WordBand::Anchor => self.anchor_frequency,
+        }
+    }
+}
+
+/// One wave per whitespace-separated word
+pub fn narrative_to_waves(text: &str) -> Vec<SemanticWave> {
+    text.split_whitespace().map(SemanticWave::from_word).collect()
+}
+
+
+/// Why a truth test passed or failed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TruthReason {
+    /// Coherence above the threshold
+    Constructive,
+    /// Waves more than a quarter turn apart: they cancel (coherence < 0.5)
+    Destructive,
+    /// Waves reinforce, but not enough to clear the threshold
+    BelowThreshold,
+}
+
+/// Explainable result of `PACEngine::truth_test_detailed`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TruthTestReport {
+    /// |fact.phase - context.phase| mod 2π
+    pub phase_diff: f64,
+    /// (cos(phase_diff) + 1) / 2
+    pub coherence: f64,
+    pub threshold: f64,
+    pub reason: TruthReason,
+}
+
+impl TruthTestReport {
+    /// Same verdict as `truth_test`
+    pub fn passed(&self) -> bool {
+        self.reason == TruthReason::Constructive
+    }
 }
 
 /// PAC (Probabilistic Amplitude Computing) Engine
@@ -72,7 +277,7 @@ impl PACEngine {
     
     /// I = |ψ₁ + ψ₂|²
     pub fn interference_intensity(&self, wave1: &SemanticWave, wave2: &SemanticWave) -> f64 {
-        let superposition = wave1.to_complex().add(&wave2.to_complex());
+        let superposition = wave1.to_complex() + wave2.to_complex();
         superposition.magnitude().powi(2)
     }
     
@@ -82,33 +287,294 @@ impl PACEngine {
         let coherence = (phase_diff.cos() + 1.0) / 2.0;
         (coherence > self.coherence_threshold, coherence)
     }
+    
+    /// `truth_test` with the phase difference, threshold and a reason
+    pub fn truth_test_detailed(
+        &self,
+        fact: &SemanticWave,
+        context: &SemanticWave,
+    ) -> TruthTestReport {
+        let phase_diff = (fact.phase - context.phase).abs() % (2.0 * PI);
+        let coherence = (phase_diff.cos() + 1.0) / 2.0;
+        let reason = if coherence > self.coherence_threshold {
+            TruthReason::Constructive
+        } else if coherence < 0.5 {
+            TruthReason::Destructive
+        } else {
+            TruthReason::BelowThreshold
+        };
+        TruthTestReport { phase_diff, coherence, threshold: self.coherence_threshold, reason }
+    }
+    
+    /// Truth test over time: samples both waves evenly across [0, duration)
+    /// and maps the normalized correlation Re(Σ f·ḡ) / √(Σ|f|² Σ|g|²) into
+    /// [0, 1] like `truth_test`. Detuned waves drift apart and score lower
+    pub fn truth_test_temporal(
+        &self,
+        fact: &SemanticWave,
+        context: &SemanticWave,
+        duration: f64,
+        samples: usize,
+    ) -> (bool, f64) {
+        let mut correlation = Complex::new(0.0, 0.0);
+        let (mut fact_energy, mut context_energy) = (0.0, 0.0);
+        for k in 0..samples {
+            let t = duration * k as f64 / samples as f64;
+            let (f, g) = (fact.sample(t), context.sample(t));
+            correlation = correlation + f * g.conj();
+            fact_energy += f.magnitude().powi(2);
+            context_energy += g.magnitude().powi(2);
+        }
+        let norm = (fact_energy * context_energy).sqrt();
+        if norm == 0.0 || !norm.is_finite() { return (false, 0.0); }
+        let coherence = ((correlation.re / norm).clamp(-1.0, 1.0) + 1.0) / 2.0;
+        (coherence > self.coherence_threshold, coherence)
+    }
+    
+    /// (t, I(t)) with I(t) = |ψ₁(t) + ψ₂(t)|², sampled evenly across
+    /// [0, duration) like `truth_test_temporal`
+    /// Frequencies are angular, so detuned waves beat at |f₁ − f₂|: I(t) =
+    /// a₁² + a₂² + 2a₁a₂·cos((f₁ − f₂)t + φ₁ − φ₂), a period of 2π/|f₁ − f₂|.
+    /// At t = 0 this is `interference_intensity`.
+    pub fn interference_series(
+        &self,
+        wave1: &SemanticWave,
+        wave2: &SemanticWave,
+        duration: f64,
+        samples: usize,
+    ) -> Vec<(f64, f64)> {
+        (0..samples)
+            .map(|k| {
+                let t = duration * k as f64 / samples as f64;
+                (t, (wave1.sample(t) + wave2.sample(t)).magnitude().powi(2))
+            })
+            .collect()
+    }
+    
+    /// I = |ψ₁ + ψ₂ + ... + ψₙ|²
+    pub fn interference_intensity_many(&self, waves: &[SemanticWave]) -> f64 {
+        Self::superpose(waves).magnitude().powi(2)
+    }
+    
+    /// Truth test for a whole claim: coherence = |Σψ| / Σ|ψ|
+    /// 1.0 when all phases align, ~0 when they cancel
+    pub fn truth_test_many(&self, waves: &[SemanticWave]) -> (bool, f64) {
+        let total_amplitude: f64 = waves.iter().map(|w| w.amplitude.abs()).sum();
+        if total_amplitude == 0.0 { return (false, 0.0); }
+        let coherence = Self::superpose(waves).magnitude() / total_amplitude;
+        (coherence > self.coherence_threshold, coherence)
+    }
+    
+    /// Minimum pairwise `truth_test` coherence inside each run of `window`
+    /// consecutive waves, as (start index, coherence)
+    /// Windows advance one wave at a time, so neighbours overlap by
+    /// `window - 1` waves and a contradiction drags down every window that
+    /// contains both of its waves. Empty when `window < 2` or the document is
+    /// shorter than one window.
+    pub fn scan_contradictions(&self, waves: &[SemanticWave], window: usize) -> Vec<(usize, f64)> {
+        if window < 2 { return Vec::new(); }
+        waves
+            .windows(window)
+            .enumerate()
+            .map(|(start, span)| {
+                let mut min = 1.0f64;
+                for (i, a) in span.iter().enumerate() {
+                    for b in &span[i + 1..] {
+                        min = min.min(self.truth_test(a, b).1);
+                    }
+                }
+                (start, min)
+            })
+            .collect()
+    }
+    
+    fn superpose(waves: &[SemanticWave]) -> Complex {
+        waves.iter().fold(Complex::new(0.0, 0.0), |acc, w| acc + w.to_complex())
+    }
 }
 
 /// Read-Shockley Grain Boundary Stress
 pub struct CrystalStress;
 
 impl CrystalStress {
+    /// Misorientation (degrees) where low-angle boundaries reach the plateau
+    pub const THETA_MAX_DEG: f64 = 15.0;
+    
+    /// Normalized Read-Shockley energy E/E_m = (θ/θ_m)·(1 − ln(θ/θ_m))
+    /// `theta` is the misorientation in degrees. Rises from 0 to 1 with zero
+    /// slope at θ_m = 15°, and stays at 1 above.
     pub fn boundary_energy(theta: f64) -> f64 {
-        if theta < 0.001 { return 0.0; }
-        if theta >= 15.0 { return 1.0; }
-        let rad = theta.to_radians();
-        (2.5 * rad * (0.5 - rad.ln())).clamp(0.0, 1.0)
+        if theta <= 0.0 { return 0.0; }
+        if theta >= Self::THETA_MAX_DEG { return 1.0; }
+        let ratio = theta / Self::THETA_MAX_DEG;
+        ratio * (1.0 - ratio.ln())
     }
 }
 
 /// Crystallization Engine
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Verdict { Crystal, Annealing, Dissolved }
 
+/// Scores below this crystallize
+pub const CRYSTAL_THRESHOLD: f64 = 0.2;
+
+/// Scores below this (and at least CRYSTAL_THRESHOLD) are still annealing
+pub const ANNEALING_THRESHOLD: f64 = 0.5;
+
+/// Coherence threshold used by `crystallize` and `crystallize_scored`
+pub const DEFAULT_COHERENCE_THRESHOLD: f64 = 0.7;
+
 pub fn crystallize(fact: &SemanticWave, narrative: &SemanticWave, orient: f64) -> Verdict {
-    let pac = PACEngine::new(0.7);
-    let (_, coherence) = pac.truth_test(fact, narrative);
+    crystallize_scored(fact, narrative, orient).0
+}
+
+/// `crystallize` with the caller's coherence threshold
+pub fn crystallize_with(
+    fact: &SemanticWave,
+    narrative: &SemanticWave,
+    orient: f64,
+    engine: &PACEngine,
+) -> Verdict {
+    crystallize_scored_with(fact, narrative, orient, engine).0
+}
+
+/// Verdict plus the blended score: half incoherence, half boundary stress, in [0, 1]
+pub fn crystallize_scored(
+    fact: &SemanticWave,
+    narrative: &SemanticWave,
+    orient: f64,
+) -> (Verdict, f64) {
+    let pac = PACEngine::new(DEFAULT_COHERENCE_THRESHOLD);
+    crystallize_scored_with(fact, narrative, orient, &pac)
+}
+
+/// `crystallize_scored` with the caller's coherence threshold
+/// Incoherence is measured against the engine's allowance (1 − threshold),
+/// relative to the default's 0.3, and capped at 1: the default threshold
+/// scores as before, a stricter one raises the score of the same waves
+pub fn crystallize_scored_with(
+    fact: &SemanticWave,
+    narrative: &SemanticWave,
+    orient: f64,
+    engine: &PACEngine,
+) -> (Verdict, f64) {
+    let report = crystallize_explained_with(fact, narrative, orient, engine);
+    (report.verdict, report.score)
+}
+
+/// Which half of the crystallization score weighed more
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CrystalFactor {
+    /// Phase mismatch between fact and narrative
+    Coherence,
+    /// Grain-boundary misorientation
+    Stress,
+}
+
+/// Explainable result of `crystallize_explained`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrystalReport {
+    /// Raw coherence from `PACEngine::truth_test`
+    pub coherence: f64,
+    /// Incoherence half of the score, in [0, 0.5]
+    pub coherence_term: f64,
+    /// Boundary-stress half of the score, in [0, 0.5]
+    pub stress_term: f64,
+    /// `coherence_term + stress_term`
+    pub score: f64,
+    pub verdict: Verdict,
+    /// The larger term; `Coherence` on a tie
+    pub dominant: CrystalFactor,
+}
+
+/// `crystallize_scored` broken down into its coherence and stress terms
+pub fn crystallize_explained(
+    fact: &SemanticWave,
+    narrative: &SemanticWave,
+    orient: f64,
+) -> CrystalReport {
+    let pac = PACEngine::new(DEFAULT_COHERENCE_THRESHOLD);
+    crystallize_explained_with(fact, narrative, orient, &pac)
+}
+
+/// `crystallize_explained` with the caller's coherence threshold
+pub fn crystallize_explained_with(
+    fact: &SemanticWave,
+    narrative: &SemanticWave,
+    orient: f64,
+    engine: &PACEngine,
+) -> CrystalReport {
+    let (_, coherence) = engine.truth_test(fact, narrative);
+    let allowance = (1.0 - engine.coherence_threshold).max(f64::EPSILON);
+    let default_allowance = 1.0 - DEFAULT_COHERENCE_THRESHOLD;
+    let incoherence = ((1.0 - coherence) * (default_allowance / allowance)).min(1.0);
     let stress = CrystalStress::boundary_energy(orient);
-    let score = (1.0 - coherence) * 0.5 + stress * 0.5;
+    let coherence_term = incoherence * 0.5;
+    let stress_term = stress * 0.5;
+    let score = coherence_term + stress_term;
+    
+    let verdict = if score < CRYSTAL_THRESHOLD { Verdict::Crystal }
+    else if score < ANNEALING_THRESHOLD { Verdict::Annealing }
+    else { Verdict::Dissolved };
+    let dominant = if stress_term > coherence_term { CrystalFactor::Stress }
+    else { CrystalFactor::Coherence };
     
-    if score < 0.2 { Verdict::Crystal }
-    else if score < 0.5 { Verdict::Annealing }
-    else { Verdict::Dissolved }
+    CrystalReport { coherence, coherence_term, stress_term, score, verdict, dominant }
+}
+
+/// Share of grain boundaries in each verdict
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundaryFractions {
+    pub crystal: f64,
+    pub annealing: f64,
+    pub dissolved: f64,
+}
+
+/// A narrative as a mesh of facts: each adjacent pair of waves meets at a
+/// grain boundary with its own misorientation (degrees)
+pub struct CrystalLattice {
+    waves: Vec<SemanticWave>,
+    orientations: Vec<f64>,
+}
+
+impl CrystalLattice {
+    /// `orientations[i]` is the angle between `waves[i]` and `waves[i + 1]`
+    pub fn new(waves: Vec<SemanticWave>, orientations: Vec<f64>) -> Result<Self, &'static str> {
+        if orientations.len() + 1 != waves.len().max(1) {
+            return Err("Need one orientation per adjacent pair of waves");
+        }
+        Ok(CrystalLattice { waves, orientations })
+    }
+    
+    /// Verdict and score for every boundary, in order
+    pub fn boundaries(&self) -> Vec<(Verdict, f64)> {
+        self.waves
+            .windows(2)
+            .zip(&self.orientations)
+            .map(|(pair, &orient)| crystallize_scored(&pair[0], &pair[1], orient))
+            .collect()
+    }
+    
+    /// Fraction of boundaries that crystallize, anneal, or dissolve
+    pub fn fractions(&self) -> BoundaryFractions {
+        let boundaries = self.boundaries();
+        let total = boundaries.len().max(1) as f64;
+        let share = |v: Verdict| boundaries.iter().filter(|(b, _)| *b == v).count() as f64 / total;
+        
+        BoundaryFractions {
+            crystal: share(Verdict::Crystal),
+            annealing: share(Verdict::Annealing),
+            dissolved: share(Verdict::Dissolved),
+        }
+    }
+    
+    /// 1 − mean boundary score; a lattice with no boundaries is fully coherent
+    pub fn coherence(&self) -> f64 {
+        let boundaries = self.boundaries();
+        if boundaries.is_empty() { return 1.0; }
+        let mean_score = boundaries.iter().map(|(_, s)| s).sum::<f64>() / boundaries.len() as f64;
+        1.0 - mean_score
+    }
 }
 
 // WASM Entry Points
@@ -120,6 +586,7 @@ pub extern "C" fn wasm_interference(a1: f64, p1: f64, a2: f64, p2: f64) -> f64 {
     )
 }
 
+#[cfg(feature = "std")]
 fn main() {
     println!("TENT v4.0 PHYSICS CORE - PAC Engine");
     let truth = SemanticWave::new(1.0, 0.1, 1.0);
@@ -129,3 +596,345 @@ fn main() {
     println!("Truth + Context: {:?}", crystallize(&truth, &context, 5.0));
     println!("Truth + Lie:     {:?}", crystallize(&truth, &lie, 90.0));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[cfg(not(feature = "std"))]
+    #[test]
+    fn test_libm_matches_std() {
+        for &x in &[-2.5_f64, -0.3, 0.7, 1.0, 3.9] {
+            assert!((FloatMath::sin(x) - x.sin()).abs() < 1e-12);
+            assert!((FloatMath::cos(x) - x.cos()).abs() < 1e-12);
+            assert!((FloatMath::atan2(x, 0.5) - x.atan2(0.5)).abs() < 1e-12);
+            assert!((FloatMath::ln(x.abs()) - x.abs().ln()).abs() < 1e-12);
+            assert!((FloatMath::log2(x.abs()) - x.abs().log2()).abs() < 1e-12);
+        }
+    }
+    
+    #[test]
+    fn test_crystallize_with_threshold() {
+        let fact = SemanticWave::new(1.0, 0.0, 1.0);
+        let narrative = SemanticWave::new(1.0, 0.5, 1.0); // coherence ≈ 0.94
+        
+        let default = crystallize_scored(&fact, &narrative, 0.0);
+        assert_eq!(default.0, Verdict::Crystal);
+        let engine = PACEngine::new(DEFAULT_COHERENCE_THRESHOLD);
+        assert_eq!(crystallize_scored_with(&fact, &narrative, 0.0, &engine), default);
+        
+        let verdict_at =
+            |threshold| crystallize_with(&fact, &narrative, 0.0, &PACEngine::new(threshold));
+        assert_eq!(verdict_at(0.9), Verdict::Crystal);
+        assert_eq!(verdict_at(0.97), Verdict::Annealing);
+        assert_eq!(verdict_at(0.99), Verdict::Dissolved);
+    }
+    
+    #[test]
+    fn test_truth_test_temporal() {
+        let engine = PACEngine::new(0.7);
+        let fact = SemanticWave::new(1.0, 0.2, 1.0);
+        
+        let (aligned, score) = engine.truth_test_temporal(&fact, &fact, 2.0 * PI, 64);
+        assert!(aligned);
+        assert!((score - 1.0).abs() < 1e-9);
+        
+        // Same instantaneous phase, but one full beat over the window
+        let detuned = SemanticWave::new(1.0, 0.2, 2.0);
+        assert!(engine.truth_test(&fact, &detuned).1 > 0.99);
+        let (coherent, detuned_score) = engine.truth_test_temporal(&fact, &detuned, 2.0 * PI, 64);
+        assert!(!coherent);
+        assert!(detuned_score < score);
+        assert!((detuned_score - 0.5).abs() < 1e-9);
+        
+        assert_eq!(engine.truth_test_temporal(&fact, &fact, 1.0, 0), (false, 0.0));
+    }
+    
+    #[test]
+    fn test_truth_test_detailed() {
+        let engine = PACEngine::new(0.7);
+        let fact = SemanticWave::new(1.0, 0.3, 1.0);
+        let at = |phase| SemanticWave::new(1.0, phase, 1.0);
+        
+        let in_phase = engine.truth_test_detailed(&fact, &at(0.3));
+        assert_eq!(in_phase.reason, TruthReason::Constructive);
+        assert!(in_phase.passed());
+        assert_eq!((in_phase.phase_diff, in_phase.coherence, in_phase.threshold), (0.0, 1.0, 0.7));
+        
+        let anti_phase = engine.truth_test_detailed(&fact, &at(0.3 + PI));
+        assert_eq!(anti_phase.reason, TruthReason::Destructive);
+        assert!(anti_phase.coherence < 1e-12);
+        
+        // coherence 0.7 exactly at phase_diff = acos(0.4); just past it misses
+        let edge = 0.4_f64.acos();
+        let near = engine.truth_test_detailed(&fact, &at(0.3 + edge + 1e-3));
+        assert_eq!(near.reason, TruthReason::BelowThreshold);
+        assert!(near.coherence > 0.69 && !near.passed());
+        assert!(engine.truth_test_detailed(&fact, &at(0.3 + edge - 1e-3)).passed());
+        
+        for phase in [0.0, 1.0, 2.0, 4.0] {
+            let (passed, coherence) = engine.truth_test(&fact, &at(phase));
+            let report = engine.truth_test_detailed(&fact, &at(phase));
+            assert_eq!((report.passed(), report.coherence), (passed, coherence));
+        }
+    }
+    
+    #[test]
+    fn test_from_word_banded() {
+        let bands = WaveBandMap::default();
+        let theorem = SemanticWave::from_word_banded("Theorem", &bands);
+        let proof = SemanticWave::from_word_banded("proof,", &bands);
+        let the = SemanticWave::from_word_banded("the", &bands);
+        let of = SemanticWave::from_word_banded("of", &bands);
+        
+        assert_eq!(bands.band("Theorem"), WordBand::Anchor);
+        assert_eq!(bands.band("of"), WordBand::Function);
+        assert_eq!(bands.band("crystal"), WordBand::Content);
+        assert_eq!(theorem.frequency, proof.frequency);
+        assert_eq!(the.frequency, of.frequency);
+        assert_ne!(theorem.frequency, the.frequency);
+        
+        // Only the carrier changes
+        let plain = SemanticWave::from_word("proof,");
+        assert_eq!((proof.amplitude, proof.phase), (plain.amplitude, plain.phase));
+        
+        // Same band stays locked; anchor vs filler drifts apart over time
+        let engine = PACEngine::new(0.7);
+        let aligned = SemanticWave { phase: theorem.phase, ..proof };
+        let filler = SemanticWave { phase: theorem.phase, ..the };
+        let window = 40.0 * PI;
+        let locked = engine.truth_test_temporal(&theorem, &aligned, window, 4096).1;
+        assert!((locked - 1.0).abs() < 1e-9);
+        assert!(engine.truth_test_temporal(&theorem, &filler, window, 4096).1 < 0.6);
+    }
+    
+    #[test]
+    fn test_complex_arithmetic() {
+        let a = Complex::new(3.0, 2.0);
+        let b = Complex::new(1.0, 4.0);
+        
+        // (3 + 2i)(1 + 4i) = 3 + 12i + 2i + 8i² = -5 + 14i
+        let product = a * b;
+        assert_eq!((product.re, product.im), (-5.0, 14.0));
+        
+        let sum = a + b;
+        assert_eq!((sum.re, sum.im), (4.0, 6.0));
+        let diff = a - b;
+        assert_eq!((diff.re, diff.im), (2.0, -2.0));
+        let scaled = a.scale(0.5);
+        assert_eq!((scaled.re, scaled.im), (1.5, 1.0));
+        
+        let conj = a.conj();
+        assert_eq!((conj.re, conj.im), (3.0, -2.0));
+        
+        // Multiplying by a unit phasor rotates
+        let rotated = Complex::from_polar(1.0, 0.25) * Complex::from_polar(1.0, 0.5);
+        assert!((rotated.phase() - 0.75).abs() < 1e-12);
+        assert!((rotated.magnitude() - 1.0).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_boundary_energy_monotonic() {
+        let mut previous = CrystalStress::boundary_energy(0.0);
+        assert_eq!(previous, 0.0);
+        
+        for step in 1..=150 {
+            let energy = CrystalStress::boundary_energy(step as f64 * 0.1);
+            assert!(energy > previous, "not increasing at {}°", step as f64 * 0.1);
+            previous = energy;
+        }
+        
+        // Continuous into the plateau
+        assert!((CrystalStress::boundary_energy(14.999) - 1.0).abs() < 1e-6);
+        assert_eq!(CrystalStress::boundary_energy(15.0), 1.0);
+        assert_eq!(CrystalStress::boundary_energy(45.0), 1.0);
+    }
+    
+    #[test]
+    fn test_crystallize_scored() {
+        let fact = SemanticWave::new(1.0, 0.1, 1.0);
+        let samples = [
+            (SemanticWave::new(1.0, 0.1, 1.0), 0.0),
+            (SemanticWave::new(1.0, 0.15, 1.0), 0.5),
+            (SemanticWave::new(1.0, 0.15, 1.0), 5.0),
+            (SemanticWave::new(1.0, PI * 0.5, 1.0), 2.0),
+            (SemanticWave::new(1.0, PI * 0.8, 1.0), 90.0),
+        ];
+        
+        let mut verdicts = Vec::new();
+        for (narrative, orient) in &samples {
+            let (verdict, score) = crystallize_scored(&fact, narrative, *orient);
+            assert!((0.0..=1.0).contains(&score));
+            
+            let expected = if score < CRYSTAL_THRESHOLD { Verdict::Crystal }
+            else if score < ANNEALING_THRESHOLD { Verdict::Annealing }
+            else { Verdict::Dissolved };
+            assert_eq!(verdict, expected);
+            assert_eq!(crystallize(&fact, narrative, *orient), verdict);
+            verdicts.push(verdict);
+        }
+        
+        assert_eq!(verdicts[0], Verdict::Crystal);
+        assert_eq!(verdicts[4], Verdict::Dissolved);
+    }
+    
+    #[test]
+    fn test_shared_word_hash() {
+        // Same reference value geometry_core and wasm_forge pin
+        assert_eq!(word_hash("truth", 0), 0x690e781);
+        assert_eq!(Poly31Hasher.hash_word("truth", 0), word_hash("truth", 0));
+        
+        let phase = (word_hash("truth", 0) as f64 * PHI) % (2.0 * PI);
+        assert_eq!(SemanticWave::from_word("truth").phase, phase);
+    }
+    
+    #[test]
+    fn test_interference_series() {
+        let engine = PACEngine::new(0.7);
+        let (fast, slow) = (SemanticWave::new(1.0, 0.0, 3.0), SemanticWave::new(1.0, 0.0, 2.5));
+        let beat = 0.5; // |f1 - f2|
+        let period = 2.0 * PI / beat;
+        
+        let series = engine.interference_series(&fast, &slow, 2.0 * period, 800);
+        assert_eq!(series.len(), 800);
+        assert_eq!(series[0].1, engine.interference_intensity(&fast, &slow));
+        for &(t, intensity) in &series {
+            assert!((intensity - (2.0 + 2.0 * (beat * t).cos())).abs() < 1e-9);
+        }
+        
+        // Silent at each half period, loud again one full period later
+        let nearest = |target: f64| {
+            series.iter().min_by(|a, b| (a.0 - target).abs().total_cmp(&(b.0 - target).abs()))
+                .unwrap().1
+        };
+        assert!(nearest(0.5 * period) < 1e-3);
+        assert!(nearest(1.5 * period) < 1e-3);
+        assert!(nearest(period) > 4.0 - 1e-3);
+        
+        // Matched frequencies never beat
+        let steady = engine.interference_series(&fast, &fast, period, 50);
+        assert!(steady.iter().all(|&(_, i)| (i - 4.0).abs() < 1e-9));
+        assert!(engine.interference_series(&fast, &slow, 1.0, 0).is_empty());
+    }
+    
+    #[test]
+    fn test_scan_contradictions() {
+        let engine = PACEngine::new(0.7);
+        let mut waves: Vec<SemanticWave> =
+            (0..20).map(|k| SemanticWave::new(1.0, 0.2 + 0.01 * k as f64, 1.0)).collect();
+        waves[11] = SemanticWave::new(1.0, 0.2 + PI, 1.0);
+        
+        let scan = engine.scan_contradictions(&waves, 4);
+        assert_eq!(scan.len(), 17);
+        assert!(scan.iter().enumerate().all(|(i, &(start, _))| start == i));
+        
+        // Exactly the windows covering wave 11 (starts 8..=11) dip
+        for &(start, coherence) in &scan {
+            if (8..=11).contains(&start) {
+                assert!(coherence < 0.01);
+            } else {
+                assert!(coherence > 0.99);
+            }
+        }
+        let worst = scan.iter().min_by(|a, b| a.1.total_cmp(&b.1)).unwrap();
+        assert!((8..=11).contains(&worst.0));
+        
+        assert!(engine.scan_contradictions(&waves, 1).is_empty());
+        assert!(engine.scan_contradictions(&waves[..3], 4).is_empty());
+    }
+    
+    #[test]
+    fn test_crystallize_explained() {
+        let fact = SemanticWave::new(1.0, 0.1, 1.0);
+        
+        // Nearly in phase, but across a high-angle boundary
+        let stressed = crystallize_explained(&fact, &SemanticWave::new(1.0, 0.15, 1.0), 40.0);
+        assert_eq!(stressed.verdict, Verdict::Dissolved);
+        assert_eq!(stressed.dominant, CrystalFactor::Stress);
+        assert_eq!(stressed.stress_term, 0.5);
+        assert!(stressed.coherence_term < 0.01);
+        
+        // Out of phase across a clean boundary
+        let opposed = crystallize_explained(&fact, &SemanticWave::new(1.0, 0.1 + PI, 1.0), 0.0);
+        assert_eq!(opposed.verdict, Verdict::Dissolved);
+        assert_eq!(opposed.dominant, CrystalFactor::Coherence);
+        assert_eq!(opposed.stress_term, 0.0);
+        
+        for report in [stressed, opposed] {
+            assert_eq!(report.score, report.coherence_term + report.stress_term);
+        }
+        let narrative = SemanticWave::new(1.0, 0.6, 1.0);
+        let report = crystallize_explained(&fact, &narrative, 3.0);
+        assert_eq!((report.verdict, report.score), crystallize_scored(&fact, &narrative, 3.0));
+    }
+    
+    #[test]
+    fn test_crystal_lattice() {
+        let coherent = CrystalLattice::new(
+            (0..6).map(|k| SemanticWave::new(1.0, 0.1 + 0.01 * k as f64, 1.0)).collect(),
+            vec![0.2, 0.1, 0.3, 0.2, 8.0],
+        ).unwrap();
+        let fractions = coherent.fractions();
+        assert!(fractions.crystal >= 0.8, "{:?}", fractions);
+        assert!(coherent.coherence() > 0.8);
+        
+        let incoherent = CrystalLattice::new(
+            (0..6).map(|k| SemanticWave::new(1.0, PI * (k % 2) as f64 + 0.1, 1.0)).collect(),
+            vec![30.0, 45.0, 20.0, 60.0, 2.0],
+        ).unwrap();
+        let fractions = incoherent.fractions();
+        assert!(fractions.dissolved >= 0.8, "{:?}", fractions);
+        assert!(incoherent.coherence() < coherent.coherence());
+        
+        let total = fractions.crystal + fractions.annealing + fractions.dissolved;
+        assert!((total - 1.0).abs() < 1e-12);
+        assert!(CrystalLattice::new(vec![SemanticWave::new(1.0, 0.0, 1.0)], vec![1.0]).is_err());
+    }
+    
+    #[test]
+    fn test_narrative_to_waves() {
+        let waves = narrative_to_waves("Energy is neither created nor destroyed");
+        assert_eq!(waves.len(), 6);
+        
+        for wave in &waves {
+            assert!((0.0..2.0 * PI).contains(&wave.phase));
+            assert!(wave.amplitude > 0.0);
+            assert_eq!(wave.frequency, BASE_FREQUENCY);
+        }
+        
+        // Deterministic, and repetitive words carry less amplitude
+        assert_eq!(SemanticWave::from_word("created").phase, waves[3].phase);
+        let repetitive = SemanticWave::from_word("aaaa");
+        assert!(repetitive.amplitude < SemanticWave::from_word("abcd").amplitude);
+        assert!(narrative_to_waves("   ").is_empty());
+    }
+    
+    #[test]
+    fn test_interference_many_aligned() {
+        let pac = PACEngine::new(0.7);
+        let waves: Vec<SemanticWave> = (0..5).map(|_| SemanticWave::new(2.0, 0.3, 1.0)).collect();
+        
+        // N identical waves: N²·amp²
+        assert!((pac.interference_intensity_many(&waves) - 25.0 * 4.0).abs() < 1e-9);
+        
+        let (is_true, coherence) = pac.truth_test_many(&waves);
+        assert!(is_true);
+        assert!((coherence - 1.0).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_interference_many_cancels() {
+        let pac = PACEngine::new(0.7);
+        let n = 6;
+        let waves: Vec<SemanticWave> = (0..n)
+            .map(|k| SemanticWave::new(1.0, 2.0 * PI * k as f64 / n as f64, 1.0))
+            .collect();
+        
+        assert!(pac.interference_intensity_many(&waves) < 1e-20);
+        
+        let (is_true, coherence) = pac.truth_test_many(&waves);
+        assert!(!is_true);
+        assert!(coherence < 1e-9);
+        assert_eq!(pac.truth_test_many(&[]), (false, 0.0));
+    }
+}
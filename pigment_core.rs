@@ -8,7 +8,8 @@
 //!
 //! "The user sees the beauty. The machine reads the truth."
 
-use std::f64::consts::PI;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
 // =============================================================================
 // CONSTANTS
@@ -23,6 +24,82 @@ pub const DELTA: f64 = 2.414213562373095;
 /// Prime for hashing operations
 pub const HASH_PRIME: u64 = 0xFFFFFFFFFFFFFFFF;
 
+/// Odd multiplier for the hash sponge rounds
+const SPONGE_MULTIPLIER: u64 = 0x9E3779B97F4A7C15;
+
+/// Permutation rounds between absorbed blocks
+const SPONGE_ROUNDS: usize = 4;
+
+/// Weight of seed-hash Hamming distance in `Pigment::distance`
+const HASH_DISTANCE_WEIGHT: f64 = 0.4;
+
+/// Weight of each scalar (resonance, density, friction) in `Pigment::distance`
+const TRAIT_DISTANCE_WEIGHT: f64 = 0.2;
+
+/// Size of `Pigment::to_bytes` output
+pub const PIGMENT_BYTES: usize = 56;
+
+/// Magic prefix of a serialized Canvas
+pub const CANVAS_MAGIC: [u8; 4] = *b"PGMT";
+
+/// Serialized Canvas format version
+pub const CANVAS_VERSION: u8 = 1;
+
+/// MAGIC(4) + VERSION(1) + WIDTH(4) + HEIGHT(4)
+const CANVAS_HEADER_LEN: usize = 13;
+
+/// Okabe-Ito colorblind-safe palette, packed 0xRRGGBBAA
+pub const OKABE_ITO: [u32; 8] = [
+    0x000000FF, // black
+    0xE69F00FF, // orange
+    0x56B4E9FF, // sky blue
+    0x009E73FF, // bluish green
+    0xF0E442FF, // yellow
+    0x0072B2FF, // blue
+    0xD55E00FF, // vermillion
+    0xCC79A7FF, // reddish purple
+];
+
+/// Seed-hash byte that picks a `palette_color` entry (bytes 0-2 are the RGB)
+const PALETTE_HASH_BYTE: usize = 3;
+
+// =============================================================================
+// CLASSIFICATION CRITERIA
+// =============================================================================
+
+/// Cutoffs used to classify a Pigment as valid, diamond, or bubble
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PigmentCriteria {
+    /// Minimum resonance for a valid pigment
+    pub valid_resonance: f32,
+    /// Minimum density for a valid pigment
+    pub valid_density: f32,
+    /// Minimum resonance for a diamond
+    pub diamond_resonance: f32,
+    /// Minimum density for a diamond
+    pub diamond_density: f32,
+    /// Maximum friction for a diamond
+    pub diamond_friction: f32,
+    /// Density below which a pigment is a bubble
+    pub bubble_density: f32,
+    /// Resonance below which a pigment is a bubble
+    pub bubble_resonance: f32,
+}
+
+impl Default for PigmentCriteria {
+    fn default() -> Self {
+        PigmentCriteria {
+            valid_resonance: 0.5,
+            valid_density: 0.1,
+            diamond_resonance: 0.8,
+            diamond_density: 0.3,
+            diamond_friction: 0.3,
+            bubble_density: 0.1,
+            bubble_resonance: 0.3,
+        }
+    }
+}
+
 // =============================================================================
 // THE PIGMENT - The Holographic Data Dot
 // =============================================================================
@@ -33,7 +110,7 @@ pub const HASH_PRIME: u64 = 0xFFFFFFFFFFFFFFFF;
 /// - seed_hash: What the Machine reads (The Micro Data)
 /// - prime_coordinate: Where it sits in the Prime Universe
 /// - resonance: How "true" this pigment is (0.0 - 1.0)
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[repr(C)] // For WASM FFI compatibility
 pub struct Pigment {
     /// RGBA color value (human-visible component)
@@ -80,24 +157,58 @@ impl Pigment {
     }
 
     /// Compute 256-bit hash from data
+    ///
+    /// A sponge over four 64-bit lanes: each 8-byte block is absorbed into
+    /// the first lane and permuted, so every input byte reaches all 32 output bytes.
+    /// Deliberately not a `TentHasher` (tent_hash.rs): color, bubbles and
+    /// Hamming distances read every seed bit, and a 64-bit word hash cannot
+    /// fill 256 of them.
     fn compute_hash(data: &[u8]) -> [u8; 32] {
-        // Simple but effective hash using prime multiplication
-        let mut hash = [0u8; 32];
-        let mut accumulator: u64 = 0x123456789ABCDEF0;
-
-        for (i, &byte) in data.iter().enumerate() {
-            accumulator = accumulator.wrapping_mul(31).wrapping_add(byte as u64);
-            hash[i % 32] ^= (accumulator >> ((i % 8) * 8)) as u8;
+        let mut state: [u64; 4] = [
+            0x123456789ABCDEF0,
+            0x0FEDCBA987654321,
+            PHI.to_bits(),
+            DELTA.to_bits(),
+        ];
+
+        // Absorb, padding the final block with 0x80 and binding the length
+        let mut blocks = data.chunks_exact(8);
+        for block in &mut blocks {
+            state[0] ^= u64::from_le_bytes(block.try_into().unwrap());
+            Self::permute(&mut state);
         }
 
-        // Final mixing pass
-        for i in 0..32 {
-            hash[i] = hash[i].wrapping_add(hash[(i + 17) % 32]);
+        let mut last = [0u8; 8];
+        let tail = blocks.remainder();
+        last[..tail.len()].copy_from_slice(tail);
+        last[tail.len()] = 0x80;
+        state[0] ^= u64::from_le_bytes(last);
+        state[1] ^= data.len() as u64;
+        Self::permute(&mut state);
+        Self::permute(&mut state);
+
+        // Squeeze
+        let mut hash = [0u8; 32];
+        for (chunk, lane) in hash.chunks_exact_mut(8).zip(state) {
+            chunk.copy_from_slice(&lane.to_le_bytes());
         }
 
         hash
     }
 
+    /// Multiply-rotate permutation of the sponge state
+    fn permute(state: &mut [u64; 4]) {
+        for _ in 0..SPONGE_ROUNDS {
+            for i in 0..4 {
+                let neighbor = state[(i + 1) % 4];
+                state[i] = (state[i] ^ neighbor.rotate_left(23))
+                    .wrapping_mul(SPONGE_MULTIPLIER)
+                    .rotate_left(31);
+                state[i] ^= state[i] >> 29;
+            }
+        }
+    }
+
     /// Convert hash to RGBA color
     fn hash_to_color(hash: &[u8; 32]) -> u32 {
         let r = hash[0];
@@ -109,42 +220,58 @@ impl Pigment {
     }
 
     /// Compute resonance score based on hash alignment with prime
+    ///
+    /// The prime acts as a modular filter: the full 256-bit hash is reduced
+    /// mod `prime` and the score is how close that residue lies to zero on
+    /// the residue circle. 1.0 means the hash is a multiple of the prime,
+    /// 0.0 means it sits exactly opposite. Primes below 2 filter nothing
+    /// and score 0.0.
     fn compute_resonance(hash: &[u8; 32], prime: u64) -> f32 {
-        // Extract 64-bit value from hash
-        let hash_value = u64::from_le_bytes([
-            hash[0], hash[1], hash[2], hash[3], hash[4], hash[5], hash[6], hash[7],
-        ]);
+        if prime < 2 {
+            return 0.0;
+        }
 
-        // Check alignment with prime using golden ratio phase
-        let phase = (hash_value as f64 * PHI) % (2.0 * PI);
-        let prime_phase = (prime as f64 * DELTA) % (2.0 * PI);
+        // hash mod prime, big-endian over all 32 bytes
+        let modulus = prime as u128;
+        let residue = hash
+            .iter()
+            .fold(0u128, |acc, &byte| ((acc << 8) | byte as u128) % modulus) as u64;
 
-        // Phase difference determines resonance
-        let diff = (phase - prime_phase).abs();
-        let normalized = 1.0 - (diff / PI);
+        // Circular distance to the nearest multiple, normalized to [0, 1]
+        let distance = residue.min(prime - residue) as f64;
+        let half = (prime / 2) as f64;
 
-        normalized.max(0.0).min(1.0) as f32
+        (1.0 - distance / half).clamp(0.0, 1.0) as f32
     }
 
-    /// Compute density from data (simplified semantic mass)
+    /// Compute density from data (semantic mass): Shannon entropy of the
+    /// byte distribution in bits per byte, normalized by 8 to [0, 1].
+    ///
+    /// Unlike the earlier unique-byte ratio, this does not saturate for
+    /// short diverse strings: "abcabc" (log2 3 bits) scores below "abcdef"
+    /// (log2 6 bits), a single repeated byte scores 0 and uniformly
+    /// distributed bytes score 1.
     fn compute_density(data: &[u8]) -> f32 {
         if data.is_empty() {
             return 0.0;
         }
 
-        // Count unique bytes (entropy approximation)
-        let mut seen = [false; 256];
-        let mut unique = 0usize;
-
+        let mut counts = [0usize; 256];
         for &byte in data {
-            if !seen[byte as usize] {
-                seen[byte as usize] = true;
-                unique += 1;
-            }
+            counts[byte as usize] += 1;
         }
 
-        // Density = unique / total (higher = denser information)
-        (unique as f32) / (data.len() as f32).max(1.0)
+        let total = data.len() as f64;
+        let entropy: f64 = counts
+            .iter()
+            .filter(|&&count| count > 0)
+            .map(|&count| {
+                let p = count as f64 / total;
+                -p * p.log2()
+            })
+            .sum();
+
+        (entropy / 8.0) as f32
     }
 
     /// Compute friction from data (aesthetic vs logic tension)
@@ -168,17 +295,98 @@ impl Pigment {
 
     /// Check if this Pigment is valid (not noise)
     pub fn is_valid(&self) -> bool {
-        self.resonance > 0.5 && self.density > 0.1
+        self.is_valid_with(&PigmentCriteria::default())
     }
 
     /// Check if this Pigment is a "Diamond" (high truth density)
     pub fn is_diamond(&self) -> bool {
-        self.resonance > 0.8 && self.density > 0.3 && self.friction < 0.3
+        self.is_diamond_with(&PigmentCriteria::default())
     }
 
     /// Check if this Pigment is a "Bubble" (empty fluff)
     pub fn is_bubble(&self) -> bool {
-        self.density < 0.1 || self.resonance < 0.3
+        self.is_bubble_with(&PigmentCriteria::default())
+    }
+
+    /// `is_valid` against custom cutoffs
+    pub fn is_valid_with(&self, criteria: &PigmentCriteria) -> bool {
+        self.resonance > criteria.valid_resonance && self.density > criteria.valid_density
+    }
+
+    /// `is_diamond` against custom cutoffs
+    pub fn is_diamond_with(&self, criteria: &PigmentCriteria) -> bool {
+        self.resonance > criteria.diamond_resonance
+            && self.density > criteria.diamond_density
+            && self.friction < criteria.diamond_friction
+    }
+
+    /// `is_bubble` against custom cutoffs
+    pub fn is_bubble_with(&self, criteria: &PigmentCriteria) -> bool {
+        self.density < criteria.bubble_density || self.resonance < criteria.bubble_resonance
+    }
+
+    /// Semantic distance to another pigment (0.0 = identical, 1.0 = opposite)
+    ///
+    /// Normalized Hamming distance over `seed_hash` blended with the
+    /// absolute differences in resonance, density and friction.
+    pub fn distance(&self, other: &Pigment) -> f64 {
+        let hash_distance = self.hamming_distance(other) as f64 / 256.0;
+
+        let trait_distance = (self.resonance - other.resonance).abs() as f64
+            + (self.density - other.density).abs() as f64
+            + (self.friction - other.friction).abs() as f64;
+
+        HASH_DISTANCE_WEIGHT * hash_distance + TRAIT_DISTANCE_WEIGHT * trait_distance
+    }
+
+    /// Number of differing `seed_hash` bits (0-256)
+    pub fn hamming_distance(&self, other: &Pigment) -> u32 {
+        self.seed_hash
+            .iter()
+            .zip(&other.seed_hash)
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum()
+    }
+
+    /// Blend two analyses of the same concept
+    ///
+    /// `weight` (clamped to [0, 1]) moves from `self` (0) to `other` (1):
+    /// resonance, density, friction and each RGBA color byte are linearly
+    /// interpolated. The result is never a plain copy, however:
+    /// - `seed_hash` is `self.seed_hash XOR other.seed_hash`, so set bits mark
+    ///   where the two seeds disagree (blending a pigment with itself gives 0)
+    /// - `prime_coordinate` comes from the pigment with the higher resonance
+    ///   (`self` on a tie), regardless of weight
+    pub fn blend(&self, other: &Pigment, weight: f64) -> Pigment {
+        let w = if weight.is_nan() { 0.0 } else { weight.clamp(0.0, 1.0) };
+        let lerp = |a: f32, b: f32| (a as f64 * (1.0 - w) + b as f64 * w) as f32;
+
+        let color_value = (0..4).fold(0u32, |color, byte| {
+            let shift = 24 - 8 * byte;
+            let a = ((self.color_value >> shift) & 0xFF) as f32;
+            let b = ((other.color_value >> shift) & 0xFF) as f32;
+            color | ((lerp(a, b).round() as u32) << shift)
+        });
+
+        let mut seed_hash = self.seed_hash;
+        for (byte, &theirs) in seed_hash.iter_mut().zip(&other.seed_hash) {
+            *byte ^= theirs;
+        }
+
+        let prime_coordinate = if other.resonance > self.resonance {
+            other.prime_coordinate
+        } else {
+            self.prime_coordinate
+        };
+
+        Pigment {
+            color_value,
+            seed_hash,
+            prime_coordinate,
+            resonance: lerp(self.resonance, other.resonance),
+            density: lerp(self.density, other.density),
+            friction: lerp(self.friction, other.friction),
+        }
     }
 
     /// Get the RGB components
@@ -190,6 +398,16 @@ impl Pigment {
         )
     }
 
+    /// Palette entry chosen by a seed-hash byte, modulo the palette length
+    /// Only the first 256 entries are reachable; an empty palette falls back
+    /// to `color_value`.
+    pub fn palette_color(&self, palette: &[u32]) -> u32 {
+        if palette.is_empty() {
+            return self.color_value;
+        }
+        palette[self.seed_hash[PALETTE_HASH_BYTE] as usize % palette.len()]
+    }
+
     /// Get color adjusted by resonance (greener = more true)
     pub fn resonance_color(&self) -> u32 {
         let (r, g, b) = self.rgb();
@@ -201,12 +419,63 @@ impl Pigment {
 
         ((truth_r as u32) << 24) | ((truth_g as u32) << 16) | ((truth_b as u32) << 8) | 255
     }
+
+    /// Fixed little-endian layout, independent of the struct's memory layout:
+    ///
+    /// | offset | size | field            |
+    /// |--------|------|------------------|
+    /// | 0      | 4    | color_value      |
+    /// | 4      | 32   | seed_hash        |
+    /// | 36     | 8    | prime_coordinate |
+    /// | 44     | 4    | resonance        |
+    /// | 48     | 4    | density          |
+    /// | 52     | 4    | friction         |
+    pub fn to_bytes(&self) -> [u8; PIGMENT_BYTES] {
+        let mut bytes = [0u8; PIGMENT_BYTES];
+        bytes[0..4].copy_from_slice(&self.color_value.to_le_bytes());
+        bytes[4..36].copy_from_slice(&self.seed_hash);
+        bytes[36..44].copy_from_slice(&self.prime_coordinate.to_le_bytes());
+        bytes[44..48].copy_from_slice(&self.resonance.to_le_bytes());
+        bytes[48..52].copy_from_slice(&self.density.to_le_bytes());
+        bytes[52..56].copy_from_slice(&self.friction.to_le_bytes());
+        bytes
+    }
+
+    /// Inverse of `to_bytes`; reads the first `PIGMENT_BYTES` bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Pigment, &'static str> {
+        if bytes.len() < PIGMENT_BYTES {
+            return Err("Truncated pigment");
+        }
+        let u32_at = |i: usize| u32::from_le_bytes(bytes[i..i + 4].try_into().unwrap());
+        let mut seed_hash = [0u8; 32];
+        seed_hash.copy_from_slice(&bytes[4..36]);
+
+        Ok(Pigment {
+            color_value: u32_at(0),
+            seed_hash,
+            prime_coordinate: u64::from_le_bytes(bytes[36..44].try_into().unwrap()),
+            resonance: f32::from_bits(u32_at(44)),
+            density: f32::from_bits(u32_at(48)),
+            friction: f32::from_bits(u32_at(52)),
+        })
+    }
 }
 
 // =============================================================================
 // THE CANVAS - A Grid of Pigments
 // =============================================================================
 
+/// How `Canvas::overlay` combines two pigments in the same cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendMode {
+    /// Keep the pigment with the higher resonance (`self` on a tie)
+    Max,
+    /// Keep the pigment with the lower resonance (`self` on a tie)
+    Min,
+    /// Even `Pigment::blend` of the two
+    Average,
+}
+
 /// A Canvas is a 2D grid of Pigments forming the "Gradient"
 pub struct Canvas {
     pub width: usize,
@@ -234,6 +503,40 @@ impl Canvas {
         }
     }
 
+    /// Build a canvas by computing each cell from its (x, y) coordinates
+    pub fn from_fn<F>(width: usize, height: usize, f: F) -> Self
+    where
+        F: Fn(usize, usize) -> Pigment,
+    {
+        let pigments = (0..width * height)
+            .map(|i| f(i % width, i / width))
+            .collect();
+
+        Canvas {
+            width,
+            height,
+            pigments,
+        }
+    }
+
+    /// Parallel `from_fn`; cells are computed across threads in row-major order
+    #[cfg(feature = "rayon")]
+    pub fn from_fn_parallel<F>(width: usize, height: usize, f: F) -> Self
+    where
+        F: Fn(usize, usize) -> Pigment + Sync,
+    {
+        let pigments = (0..width * height)
+            .into_par_iter()
+            .map(|i| f(i % width, i / width))
+            .collect();
+
+        Canvas {
+            width,
+            height,
+            pigments,
+        }
+    }
+
     /// Set a pigment at position
     pub fn set(&mut self, x: usize, y: usize, pigment: Pigment) {
         if x < self.width && y < self.height {
@@ -250,19 +553,85 @@ impl Canvas {
         }
     }
 
+    /// Iterate over pigments in row-major order with their (x, y) coordinates
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &Pigment)> {
+        let width = self.width.max(1);
+        self.pigments
+            .iter()
+            .enumerate()
+            .map(move |(i, p)| (i % width, i / width, p))
+    }
+
+    /// A row of pigments (empty if out of range)
+    pub fn row(&self, y: usize) -> &[Pigment] {
+        if y < self.height {
+            &self.pigments[y * self.width..(y + 1) * self.width]
+        } else {
+            &[]
+        }
+    }
+
+    /// A column of pigments (empty if out of range)
+    pub fn column(&self, x: usize) -> Vec<&Pigment> {
+        if x < self.width {
+            self.pigments.iter().skip(x).step_by(self.width).collect()
+        } else {
+            Vec::new()
+        }
+    }
+
     /// Calculate average resonance of the canvas
     pub fn average_resonance(&self) -> f32 {
         if self.pigments.is_empty() {
             return 0.0;
         }
 
-        let sum: f32 = self.pigments.iter().map(|p| p.resonance).sum();
+        // Summed per row, then across rows, so the parallel version matches exactly
+        let sum: f32 = self
+            .pigments
+            .chunks(self.width.max(1))
+            .map(Self::row_resonance)
+            .sum();
+        sum / (self.pigments.len() as f32)
+    }
+
+    /// Parallel `average_resonance`; bit-identical to the sequential result
+    #[cfg(feature = "rayon")]
+    pub fn average_resonance_parallel(&self) -> f32 {
+        if self.pigments.is_empty() {
+            return 0.0;
+        }
+
+        let row_sums: Vec<f32> = self
+            .pigments
+            .par_chunks(self.width.max(1))
+            .map(Self::row_resonance)
+            .collect();
+        let sum: f32 = row_sums.into_iter().sum();
         sum / (self.pigments.len() as f32)
     }
 
+    fn row_resonance(row: &[Pigment]) -> f32 {
+        row.iter().map(|p| p.resonance).sum()
+    }
+
     /// Count diamonds in the canvas
     pub fn diamond_count(&self) -> usize {
-        self.pigments.iter().filter(|p| p.is_diamond()).count()
+        self.diamond_count_with(&PigmentCriteria::default())
+    }
+
+    /// Count diamonds against custom cutoffs
+    pub fn diamond_count_with(&self, criteria: &PigmentCriteria) -> usize {
+        self.pigments
+            .iter()
+            .filter(|p| p.is_diamond_with(criteria))
+            .count()
+    }
+
+    /// Parallel `diamond_count`
+    #[cfg(feature = "rayon")]
+    pub fn diamond_count_parallel(&self) -> usize {
+        self.pigments.par_iter().filter(|p| p.is_diamond()).count()
     }
 
     /// Count bubbles (invalid pigments) in the canvas
@@ -270,10 +639,230 @@ impl Canvas {
         self.pigments.iter().filter(|p| p.is_bubble()).count()
     }
 
+    /// Document quality in [-1, 1]: (diamonds - bubbles) / cells
+    ///
+    /// +1 is an all-diamond canvas, -1 all bubbles; an empty canvas scores 0.
+    pub fn quality_index(&self) -> f64 {
+        if self.pigments.is_empty() {
+            return 0.0;
+        }
+        let diamonds = self.diamond_count() as f64;
+        let bubbles = self.bubble_count() as f64;
+        ((diamonds - bubbles) / self.pigments.len() as f64).clamp(-1.0, 1.0)
+    }
+
+    /// Grid coordinates of the diamond closest to (x, y)
+    pub fn nearest_diamond(&self, x: usize, y: usize) -> Option<(usize, usize)> {
+        self.iter()
+            .filter(|(_, _, p)| p.is_diamond())
+            .map(|(dx, dy, _)| (dx, dy))
+            .min_by_key(|&(dx, dy)| {
+                let ddx = dx.abs_diff(x);
+                let ddy = dy.abs_diff(y);
+                ddx * ddx + ddy * ddy
+            })
+    }
+
     /// Generate a heat map of resonance values
     pub fn resonance_heatmap(&self) -> Vec<u32> {
         self.pigments.iter().map(|p| p.resonance_color()).collect()
     }
+
+    /// Image buffer of `resonance_color`s for an image encoder: row-major,
+    /// 4 bytes per cell in R, G, B, A order (each packed 0xRRGGBBAA color
+    /// written big-endian), `width * height * 4` bytes in total
+    pub fn to_rgba(&self) -> Vec<u8> {
+        self.rgba_bytes(Pigment::resonance_color)
+    }
+
+    /// `to_rgba` using each pigment's base `color_value` instead
+    pub fn to_rgba_raw(&self) -> Vec<u8> {
+        self.rgba_bytes(|p| p.color_value)
+    }
+
+    /// `to_rgba` using each pigment's `palette_color`, e.g. with `OKABE_ITO`
+    pub fn to_rgba_palette(&self, palette: &[u32]) -> Vec<u8> {
+        self.rgba_bytes(|p| p.palette_color(palette))
+    }
+
+    fn rgba_bytes(&self, color: impl Fn(&Pigment) -> u32) -> Vec<u8> {
+        self.pigments.iter().flat_map(|p| color(p).to_be_bytes()).collect()
+    }
+
+    /// Count of pigment resonances in `bins` equal-width bins over [0, 1]
+    pub fn resonance_histogram(&self, bins: usize) -> Vec<usize> {
+        self.histogram(bins, |p| p.resonance)
+    }
+
+    /// Count of pigment densities in `bins` equal-width bins over [0, 1]
+    pub fn density_histogram(&self, bins: usize) -> Vec<usize> {
+        self.histogram(bins, |p| p.density)
+    }
+
+    /// Count of pigment frictions in `bins` equal-width bins over [0, 1]
+    pub fn friction_histogram(&self, bins: usize) -> Vec<usize> {
+        self.histogram(bins, |p| p.friction)
+    }
+
+    /// Bucket one trait of every pigment; values outside [0, 1] land in the
+    /// end bins and NaN is not counted
+    fn histogram<F>(&self, bins: usize, value: F) -> Vec<usize>
+    where
+        F: Fn(&Pigment) -> f32,
+    {
+        let mut counts = vec![0; bins];
+        if bins == 0 {
+            return counts;
+        }
+
+        for v in self.pigments.iter().map(value).filter(|v| !v.is_nan()) {
+            let bin = (v.clamp(0.0, 1.0) * bins as f32) as usize;
+            counts[bin.min(bins - 1)] += 1;
+        }
+        counts
+    }
+
+    /// MAGIC + VERSION + WIDTH(u32 LE) + HEIGHT(u32 LE), then each pigment's
+    /// `to_bytes` in row-major order
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(CANVAS_HEADER_LEN + self.pigments.len() * PIGMENT_BYTES);
+        bytes.extend_from_slice(&CANVAS_MAGIC);
+        bytes.push(CANVAS_VERSION);
+        bytes.extend_from_slice(&(self.width as u32).to_le_bytes());
+        bytes.extend_from_slice(&(self.height as u32).to_le_bytes());
+        for pigment in &self.pigments {
+            bytes.extend_from_slice(&pigment.to_bytes());
+        }
+        bytes
+    }
+
+    /// Inverse of `serialize`
+    pub fn deserialize(bytes: &[u8]) -> Result<Canvas, &'static str> {
+        if bytes.len() < CANVAS_HEADER_LEN {
+            return Err("Truncated canvas header");
+        }
+        if bytes[0..4] != CANVAS_MAGIC {
+            return Err("Not a serialized canvas");
+        }
+        if bytes[4] != CANVAS_VERSION {
+            return Err("Unsupported canvas version");
+        }
+        let width = u32::from_le_bytes(bytes[5..9].try_into().unwrap()) as usize;
+        let height = u32::from_le_bytes(bytes[9..13].try_into().unwrap()) as usize;
+
+        let body = &bytes[CANVAS_HEADER_LEN..];
+        let expected = width
+            .checked_mul(height)
+            .and_then(|cells| cells.checked_mul(PIGMENT_BYTES))
+            .ok_or("Canvas dimensions overflow")?;
+        if body.len() != expected {
+            return Err("Canvas length mismatch");
+        }
+
+        let pigments = body
+            .chunks_exact(PIGMENT_BYTES)
+            .map(Pigment::from_bytes)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Canvas {
+            width,
+            height,
+            pigments,
+        })
+    }
+
+    /// Combine two analyses of the same document cell by cell, e.g. under
+    /// two primes; `Min` keeps only what is diamond under both
+    pub fn overlay(&self, other: &Canvas, mode: BlendMode) -> Result<Canvas, &'static str> {
+        if (self.width, self.height) != (other.width, other.height)
+            || self.pigments.len() != other.pigments.len()
+        {
+            return Err("Canvas dimensions differ");
+        }
+
+        let pigments = self
+            .pigments
+            .iter()
+            .zip(&other.pigments)
+            .map(|(mine, theirs)| match mode {
+                BlendMode::Max if theirs.resonance > mine.resonance => *theirs,
+                BlendMode::Min if theirs.resonance < mine.resonance => *theirs,
+                BlendMode::Max | BlendMode::Min => *mine,
+                BlendMode::Average => mine.blend(theirs, 0.5),
+            })
+            .collect();
+
+        Ok(Canvas {
+            width: self.width,
+            height: self.height,
+            pigments,
+        })
+    }
+
+    /// Collapse pigments whose seed hashes lie within `hamming_radius` bits
+    /// of each other, returning how many cells were merged
+    ///
+    /// Pigments are visited from highest resonance down (row-major on ties);
+    /// each one not yet merged becomes a representative, and every unmerged
+    /// pigment within the radius of it is replaced by a copy of it. So the
+    /// highest-resonance member of a group is kept, and a group spans at most
+    /// `hamming_radius` bits from its representative.
+    pub fn dedupe(&mut self, hamming_radius: u32) -> usize {
+        let mut order: Vec<usize> = (0..self.pigments.len()).collect();
+        order.sort_by(|&a, &b| {
+            self.pigments[b].resonance.total_cmp(&self.pigments[a].resonance)
+        });
+
+        let mut merged = vec![false; self.pigments.len()];
+        let mut count = 0;
+        for (rank, &rep) in order.iter().enumerate() {
+            if merged[rep] {
+                continue;
+            }
+            let representative = self.pigments[rep];
+            for &other in &order[rank + 1..] {
+                if !merged[other]
+                    && representative.hamming_distance(&self.pigments[other]) <= hamming_radius
+                {
+                    self.pigments[other] = representative;
+                    merged[other] = true;
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+}
+
+// =============================================================================
+// SCORING API - One call from text to metrics
+// =============================================================================
+
+/// All pigment metrics for a text, as plain values
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PigmentScore {
+    pub resonance: f32,
+    pub density: f32,
+    pub friction: f32,
+    pub is_diamond: bool,
+    pub is_bubble: bool,
+    /// Resonance-adjusted RGBA, as `Pigment::resonance_color`
+    pub color: u32,
+}
+
+/// Score a text without keeping the `Pigment` around
+/// The native counterpart of the WASM `analyze_text`/`is_diamond` exports.
+pub fn score_text(text: &str, prime: u64) -> PigmentScore {
+    let pigment = Pigment::from_text(text, prime);
+    PigmentScore {
+        resonance: pigment.resonance,
+        density: pigment.density,
+        friction: pigment.friction,
+        is_diamond: pigment.is_diamond(),
+        is_bubble: pigment.is_bubble(),
+        color: pigment.resonance_color(),
+    }
 }
 
 // =============================================================================
@@ -312,6 +901,20 @@ pub fn is_diamond(text: &str, prime: u64) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_score_text() {
+        for text in ["E = mc²", "synergy synergy synergy synergy", ""] {
+            let pigment = Pigment::from_text(text, 7);
+            let score = score_text(text, 7);
+            assert_eq!(score.resonance.to_bits(), pigment.resonance.to_bits());
+            assert_eq!(score.density.to_bits(), pigment.density.to_bits());
+            assert_eq!(score.friction.to_bits(), pigment.friction.to_bits());
+            assert_eq!(score.is_diamond, pigment.is_diamond());
+            assert_eq!(score.is_bubble, pigment.is_bubble());
+            assert_eq!(score.color, pigment.resonance_color());
+        }
+    }
+
     #[test]
     fn test_pigment_creation() {
         let pigment = Pigment::from_text("E = mc²", 2);
@@ -343,6 +946,362 @@ mod tests {
         assert!(truth.density >= fluff.density);
     }
 
+    #[test]
+    fn test_hash_avalanche() {
+        let mut flipped = 0u32;
+        let mut trials = 0u32;
+
+        for i in 0..64u32 {
+            let base = format!("concept {}", i).into_bytes();
+            let before = Pigment::compute_hash(&base);
+
+            for pos in 0..base.len() {
+                let mut changed = base.clone();
+                changed[pos] ^= 1 << (i % 8);
+                let after = Pigment::compute_hash(&changed);
+
+                flipped += before
+                    .iter()
+                    .zip(&after)
+                    .map(|(a, b)| (a ^ b).count_ones())
+                    .sum::<u32>();
+                trials += 1;
+            }
+        }
+
+        // Roughly half of the 256 output bits should flip
+        let average = flipped as f64 / trials as f64;
+        assert!((average - 128.0).abs() < 4.0, "average flipped bits {}", average);
+
+        // Short texts no longer share leading bytes
+        let a = Pigment::compute_hash(b"a");
+        let b = Pigment::compute_hash(b"b");
+        assert_ne!(a[..3], b[..3]);
+    }
+
+    fn diamond() -> Pigment {
+        Pigment {
+            color_value: 0x00FF00FF,
+            seed_hash: [7; 32],
+            prime_coordinate: 2,
+            resonance: 0.9,
+            density: 0.5,
+            friction: 0.1,
+        }
+    }
+
+    #[test]
+    fn test_relaxed_criteria() {
+        let near_miss = Pigment {
+            resonance: 0.79,
+            ..diamond()
+        };
+        assert!(!near_miss.is_diamond());
+
+        let relaxed = PigmentCriteria {
+            diamond_resonance: 0.75,
+            ..PigmentCriteria::default()
+        };
+        assert!(near_miss.is_diamond_with(&relaxed));
+
+        let mut canvas = Canvas::new(2, 2);
+        canvas.set(1, 1, near_miss);
+        assert_eq!(canvas.diamond_count(), 0);
+        assert_eq!(canvas.diamond_count_with(&relaxed), 1);
+    }
+
+    #[test]
+    fn test_blend() {
+        let truth = Pigment::from_text("Energy is conserved", 7);
+        let strong = diamond();
+        let xor: Vec<u8> = truth
+            .seed_hash
+            .iter()
+            .zip(&strong.seed_hash)
+            .map(|(a, b)| a ^ b)
+            .collect();
+
+        // Weight 0 is self and weight 1 is other, except for hash and prime
+        let start = truth.blend(&strong, 0.0);
+        assert_eq!(
+            (start.color_value, start.resonance, start.density, start.friction),
+            (truth.color_value, truth.resonance, truth.density, truth.friction)
+        );
+        let end = truth.blend(&strong, 1.0);
+        assert_eq!(
+            (end.color_value, end.resonance, end.density, end.friction),
+            (strong.color_value, strong.resonance, strong.density, strong.friction)
+        );
+        for blended in [start, end] {
+            assert_eq!(blended.seed_hash.to_vec(), xor);
+            assert_eq!(blended.prime_coordinate, strong.prime_coordinate);
+        }
+
+        let mid = truth.blend(&strong, 0.5);
+        let lo = truth.resonance.min(strong.resonance);
+        let hi = truth.resonance.max(strong.resonance);
+        assert!(mid.resonance > lo && mid.resonance < hi);
+        assert_eq!(truth.blend(&strong, 7.0), end);
+        assert_eq!(truth.blend(&truth, 0.3).seed_hash, [0; 32]);
+    }
+
+    #[test]
+    fn test_histograms() {
+        let canvas = Canvas::from_fn(4, 4, |_, _| diamond());
+        let resonance = canvas.resonance_histogram(10);
+        assert_eq!(resonance.len(), 10);
+        assert_eq!(resonance[9], 16);
+        assert_eq!(resonance.iter().sum::<usize>(), 16);
+        assert_eq!(canvas.density_histogram(4), vec![0, 0, 16, 0]);
+        assert_eq!(canvas.friction_histogram(4), vec![16, 0, 0, 0]);
+
+        let empty = Canvas::new(0, 0);
+        assert_eq!(empty.resonance_histogram(5), vec![0; 5]);
+        assert!(canvas.resonance_histogram(0).is_empty());
+    }
+
+    #[test]
+    fn test_entropy_density() {
+        let uniform: Vec<u8> = (0..4096).map(|i| (i % 256) as u8).collect();
+        assert!(Pigment::compute_density(&uniform) > 0.99);
+
+        assert_eq!(Pigment::compute_density(&[b'a'; 64]), 0.0);
+
+        let prose = Pigment::compute_density(
+            b"The Riemann Hypothesis states that all non-trivial zeros have real part 1/2.",
+        );
+        assert!(prose > 0.3 && prose < 0.8, "prose density {}", prose);
+
+        assert!(Pigment::compute_density(b"abcabc") < Pigment::compute_density(b"abcdef"));
+    }
+
+    #[test]
+    fn test_byte_round_trip() {
+        let pigment = Pigment::from_text("Energy is conserved", 7);
+        let bytes = pigment.to_bytes();
+        assert_eq!(&bytes[0..4], &pigment.color_value.to_le_bytes());
+        assert_eq!(Pigment::from_bytes(&bytes), Ok(pigment));
+
+        let canvas = Canvas::from_fn(3, 2, |x, y| {
+            Pigment::from_text(&format!("Cell {},{}", x, y), 13)
+        });
+        let restored = Canvas::deserialize(&canvas.serialize()).unwrap();
+        assert_eq!((restored.width, restored.height), (3, 2));
+        assert_eq!(restored.pigments, canvas.pigments);
+    }
+
+    #[test]
+    fn test_truncated_bytes() {
+        let bytes = diamond().to_bytes();
+        assert!(Pigment::from_bytes(&bytes[..PIGMENT_BYTES - 1]).is_err());
+
+        let serialized = Canvas::new(2, 2).serialize();
+        assert!(Canvas::deserialize(&serialized[..serialized.len() - 1]).is_err());
+        assert!(Canvas::deserialize(&serialized[..5]).is_err());
+        assert!(Canvas::deserialize(b"NOPE\x01\0\0\0\0\0\0\0\0").is_err());
+    }
+
+    #[test]
+    fn test_distance() {
+        let a = Pigment::from_text("Energy is conserved", 7);
+        let b = Pigment::from_text("Energy is conserved", 7);
+        assert_eq!(a.distance(&b), 0.0);
+
+        let c = Pigment::from_text("leverage synergy", 7);
+        let d = a.distance(&c);
+        assert!(d > 0.0 && d <= 1.0);
+        assert_eq!(d, c.distance(&a));
+    }
+
+    #[test]
+    fn test_nearest_diamond() {
+        let mut canvas = Canvas::new(8, 8);
+        assert_eq!(canvas.nearest_diamond(3, 3), None);
+
+        canvas.set(0, 0, diamond());
+        canvas.set(6, 5, diamond());
+        assert_eq!(canvas.nearest_diamond(1, 1), Some((0, 0)));
+        assert_eq!(canvas.nearest_diamond(7, 7), Some((6, 5)));
+    }
+
+    #[test]
+    fn test_resonance_depends_on_prime() {
+        let text = "Entropy never decreases in an isolated system";
+        let scores: Vec<f32> = [2, 17, 97]
+            .iter()
+            .map(|&p| Pigment::from_text(text, p).resonance)
+            .collect();
+
+        // Reproducible
+        for (&p, &score) in [2, 17, 97].iter().zip(&scores) {
+            assert_eq!(Pigment::from_text(text, p).resonance, score);
+        }
+
+        // Each prime filters the same hash differently
+        assert_ne!(scores[0], scores[1]);
+        assert_ne!(scores[1], scores[2]);
+        assert_ne!(scores[0], scores[2]);
+
+        // The score is the residue's closeness to zero
+        let hash = Pigment::compute_hash(text.as_bytes());
+        let residue = hash.iter().fold(0u64, |acc, &b| (acc * 256 + b as u64) % 97);
+        let expected = 1.0 - residue.min(97 - residue) as f64 / 48.0;
+        assert_eq!(scores[2], expected as f32);
+
+        assert_eq!(Pigment::from_text(text, 0).resonance, 0.0);
+    }
+
+    #[test]
+    fn test_canvas_iteration() {
+        let mut canvas = Canvas::new(4, 3);
+        for y in 0..3 {
+            for x in 0..4 {
+                canvas.set(x, y, Pigment::from_text(&format!("{},{}", x, y), 2));
+            }
+        }
+
+        let cells: Vec<(usize, usize)> = canvas.iter().map(|(x, y, _)| (x, y)).collect();
+        assert_eq!(cells.len(), 12);
+        assert_eq!(cells[0], (0, 0));
+        assert_eq!(cells[1], (1, 0));
+        assert_eq!(cells[4], (0, 1));
+        assert_eq!(cells[11], (3, 2));
+
+        let row = canvas.row(1);
+        assert_eq!(row.len(), 4);
+        for (x, p) in row.iter().enumerate() {
+            assert_eq!(p.seed_hash, canvas.get(x, 1).unwrap().seed_hash);
+        }
+        assert!(canvas.row(3).is_empty());
+
+        let column = canvas.column(2);
+        assert_eq!(column.len(), 3);
+        assert_eq!(column[2].seed_hash, canvas.get(2, 2).unwrap().seed_hash);
+        assert!(canvas.column(4).is_empty());
+    }
+
+    #[test]
+    fn test_dedupe() {
+        let words = ["truth", "echo", "truth", "signal", "echo", "truth"];
+        let mut canvas = Canvas::from_fn(3, 2, |x, y| Pigment::from_text(words[y * 3 + x], 7));
+
+        // Two more "truth" cells and one more "echo" collapse at radius 0
+        assert_eq!(canvas.dedupe(0), 3);
+        for (i, word) in words.iter().enumerate() {
+            assert_eq!(canvas.pigments[i], Pigment::from_text(word, 7));
+        }
+
+        // A near-identical hash merges into the higher-resonance pigment
+        let strong = Pigment { resonance: 0.9, ..Pigment::from_text("truth", 7) };
+        let mut seed_hash = strong.seed_hash;
+        seed_hash[5] ^= 0b101;
+        let weak = Pigment { seed_hash, resonance: 0.2, ..strong };
+        assert_eq!(strong.hamming_distance(&weak), 2);
+
+        let mut near = Canvas::from_fn(2, 1, |x, _| [weak, strong][x]);
+        assert_eq!(near.dedupe(1), 0);
+        assert_eq!(near.pigments, [weak, strong]);
+        assert_eq!(near.dedupe(2), 1);
+        assert_eq!(near.pigments, [strong, strong]);
+    }
+
+    #[test]
+    fn test_palette_color() {
+        let slot = |byte: u8| {
+            let mut seed_hash = [0u8; 32];
+            seed_hash[PALETTE_HASH_BYTE] = byte;
+            Pigment { seed_hash, color_value: byte as u32, ..diamond() }
+        };
+
+        // 3 and 11 share slot 3 of 8, even with different base colors
+        assert_eq!(slot(3).palette_color(&OKABE_ITO), OKABE_ITO[3]);
+        assert_eq!(slot(11).palette_color(&OKABE_ITO), slot(3).palette_color(&OKABE_ITO));
+        assert_ne!(slot(4).palette_color(&OKABE_ITO), slot(3).palette_color(&OKABE_ITO));
+
+        let text = Pigment::from_text("deterministic", 7);
+        assert_eq!(text.palette_color(&OKABE_ITO), text.palette_color(&OKABE_ITO));
+        assert!(OKABE_ITO.contains(&text.palette_color(&OKABE_ITO)));
+
+        // Empty falls back to the base color; oversized wraps at the hash byte
+        assert_eq!(slot(200).palette_color(&[]), 200);
+        let wide: Vec<u32> = (0..1000).collect();
+        assert_eq!(slot(255).palette_color(&wide), 255);
+        assert_eq!(slot(9).palette_color(&[0xABCDEFFF]), 0xABCDEFFF);
+
+        let mut canvas = Canvas::new(3, 2);
+        canvas.set(2, 1, slot(14));
+        let rgba = canvas.to_rgba_palette(&OKABE_ITO);
+        assert_eq!(rgba.len(), 3 * 2 * 4);
+        assert_eq!(rgba[20..24], OKABE_ITO[6].to_be_bytes());
+        assert_eq!(rgba[0..4], OKABE_ITO[0].to_be_bytes());
+        assert_eq!(canvas.to_rgba_palette(&[]), canvas.to_rgba_raw());
+    }
+
+    #[test]
+    fn test_quality_index() {
+        assert_eq!(Canvas::new(0, 0).quality_index(), 0.0);
+
+        // A blank canvas is all bubbles
+        let mut canvas = Canvas::new(4, 2);
+        assert_eq!(canvas.quality_index(), -1.0);
+
+        // 5 diamonds, 1 neutral, 2 bubbles over 8 cells
+        let neutral = Pigment { resonance: 0.6, density: 0.2, ..diamond() };
+        assert!(!neutral.is_diamond() && !neutral.is_bubble());
+        for x in 0..4 {
+            canvas.set(x, 0, diamond());
+        }
+        canvas.set(0, 1, diamond());
+        canvas.set(1, 1, neutral);
+        assert_eq!(canvas.quality_index(), (5.0 - 2.0) / 8.0);
+
+        for x in 0..4 {
+            canvas.set(x, 1, diamond());
+        }
+        assert_eq!(canvas.quality_index(), 1.0);
+    }
+
+    #[test]
+    fn test_to_rgba() {
+        let mut canvas = Canvas::new(5, 3);
+        let pigment = Pigment { color_value: 0x11223344, resonance: 1.0, ..canvas.pigments[0] };
+        canvas.set(1, 0, pigment);
+
+        let raw = canvas.to_rgba_raw();
+        assert_eq!(raw.len(), 5 * 3 * 4);
+        assert_eq!(raw[4..8], [0x11, 0x22, 0x33, 0x44]);
+        assert_eq!(raw[0..4], [0, 0, 0, 255]);
+
+        // resonance 1: red drops out, green gains 128, blue halves
+        let rgba = canvas.to_rgba();
+        assert_eq!(rgba.len(), 5 * 3 * 4);
+        assert_eq!(rgba[4..8], [0x00, 0x22 + 128, 0x33 / 2, 255]);
+        assert_eq!(rgba[4..8], pigment.resonance_color().to_be_bytes());
+    }
+
+    #[test]
+    fn test_overlay() {
+        let analyze = |prime| {
+            Canvas::from_fn(4, 3, |x, y| Pigment::from_text(&format!("Cell {},{}", x, y), prime))
+        };
+        let (a, b) = (analyze(2), analyze(13));
+
+        let max = a.overlay(&b, BlendMode::Max).unwrap();
+        let min = a.overlay(&b, BlendMode::Min).unwrap();
+        let avg = a.overlay(&b, BlendMode::Average).unwrap();
+        for (i, cell) in max.pigments.iter().enumerate() {
+            let (p, q) = (&a.pigments[i], &b.pigments[i]);
+            assert_eq!(cell.resonance, p.resonance.max(q.resonance));
+            assert_eq!(min.pigments[i].resonance, p.resonance.min(q.resonance));
+            assert_eq!(avg.pigments[i], p.blend(q, 0.5));
+        }
+        // The winner's color comes along with its resonance
+        assert!(max.pigments.iter().all(|c| a.pigments.contains(c) || b.pigments.contains(c)));
+
+        let mismatched = a.overlay(&Canvas::new(3, 4), BlendMode::Max);
+        assert_eq!(mismatched.err(), Some("Canvas dimensions differ"));
+    }
+
     #[test]
     fn test_canvas() {
         let mut canvas = Canvas::new(10, 10);
@@ -366,4 +1325,46 @@ mod tests {
             canvas.width, canvas.height, avg, diamonds, bubbles
         );
     }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_matches_sequential() {
+        let cell = |x: usize, y: usize| Pigment::from_text(&format!("Cell {},{}", x, y), 17);
+
+        let sequential = Canvas::from_fn(37, 23, cell);
+        let parallel = Canvas::from_fn_parallel(37, 23, cell);
+
+        assert_eq!(sequential.pigments, parallel.pigments);
+        assert_eq!(
+            sequential.average_resonance().to_bits(),
+            parallel.average_resonance_parallel().to_bits()
+        );
+        assert_eq!(sequential.diamond_count(), parallel.diamond_count_parallel());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_large_grid() {
+        use std::time::Instant;
+
+        let cell = |x: usize, y: usize| Pigment::from_text(&format!("Cell {},{}", x, y), 17);
+
+        let start = Instant::now();
+        let sequential = Canvas::from_fn(512, 512, cell);
+        let sequential_time = start.elapsed();
+
+        let start = Instant::now();
+        let parallel = Canvas::from_fn_parallel(512, 512, cell);
+        let parallel_time = start.elapsed();
+
+        println!(
+            "512x512: sequential={:?}, parallel={:?}",
+            sequential_time, parallel_time
+        );
+        assert_eq!(sequential.pigments, parallel.pigments);
+        assert_eq!(
+            sequential.average_resonance().to_bits(),
+            parallel.average_resonance_parallel().to_bits()
+        );
+    }
 }
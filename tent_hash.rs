@@ -0,0 +1,102 @@
+//! TENT Shared Word Hashing
+//! ========================
+//! One word-to-number mapping for the whole pipeline.
+//!
+//! geometry_core and physics_core include this file with `#[path]` (and
+//! wasm_forge reaches it through geometry_core), so `word_hash` gives the
+//! same value everywhere. Point `DefaultTentHasher` at another `TentHasher`
+//! to change every module at once. The default is the base-31 hash
+//! wasm_forge and physics_core always used, so their output is unchanged.
+//!
+//! geometry_core's surface placement (`narrative_hash`) deliberately stays
+//! on `Fnv1aHasher`: it replaced the base-31 hash there because words
+//! sharing a prefix landed on the same cells. Pigment seed hashes and
+//! validator fingerprints are content digests, not word hashes, and stay
+//! separate.
+//!
+//! The technical anchor words live here too, so wasm_forge's lexicon and
+//! physics_core's frequency bands read one list.
+//!
+//! Only `core` is used, so no_std builds can include it.
+
+/// FNV-1a 64-bit offset basis
+pub const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+
+/// FNV-1a 64-bit prime
+pub const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Maps words (or any bytes) to 64-bit hashes
+pub trait TentHasher {
+    /// Hash a sequence of code units (bytes or chars); different seeds give
+    /// decorrelated hashes
+    fn hash_units(&self, units: impl IntoIterator<Item = u64>, seed: u64) -> u64;
+
+    /// Hash raw bytes
+    fn hash_bytes(&self, bytes: &[u8], seed: u64) -> u64 {
+        self.hash_units(bytes.iter().map(|&byte| byte as u64), seed)
+    }
+
+    /// Hash a word's UTF-8 bytes
+    fn hash_word(&self, word: &str, seed: u64) -> u64 {
+        self.hash_bytes(word.as_bytes(), seed)
+    }
+
+    /// Hash a word's chars; agrees with `hash_word` on ASCII
+    fn hash_chars(&self, word: &str, seed: u64) -> u64 {
+        self.hash_units(word.chars().map(|c| c as u64), seed)
+    }
+}
+
+/// Polynomial base-31 hash with a position mix, the seed as its start value
+/// (wasm_forge's and physics_core's original word hash)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Poly31Hasher;
+
+impl TentHasher for Poly31Hasher {
+    fn hash_units(&self, units: impl IntoIterator<Item = u64>, seed: u64) -> u64 {
+        units.into_iter().enumerate().fold(seed, |hash, (i, unit)| {
+            hash.wrapping_mul(31).wrapping_add(unit) ^ (i as u64).wrapping_mul(17)
+        })
+    }
+}
+
+/// FNV-1a with the seed folded into the offset basis
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Fnv1aHasher;
+
+impl TentHasher for Fnv1aHasher {
+    fn hash_units(&self, units: impl IntoIterator<Item = u64>, seed: u64) -> u64 {
+        units
+            .into_iter()
+            .fold(FNV_OFFSET ^ seed, |hash, unit| (hash ^ unit).wrapping_mul(FNV_PRIME))
+    }
+}
+
+/// Technical anchor words (heavy matter - positive density)
+pub const ANCHOR_WORDS: &[&str] = &[
+    "riemann",
+    "zeta",
+    "prime",
+    "theorem",
+    "proof",
+    "prove",
+    "derivative",
+    "integral",
+    "tensor",
+    "quantum",
+    "entropy",
+    "thermodynamic",
+    "algorithm",
+    "function",
+    "equation",
+    "hypothesis",
+    "axiom",
+];
+
+/// The hasher every word-hashing site routes through
+pub type DefaultTentHasher = Poly31Hasher;
+
+/// Hash a word with `DefaultTentHasher`
+pub fn word_hash(word: &str, seed: u64) -> u64 {
+    DefaultTentHasher::default().hash_word(word, seed)
+}
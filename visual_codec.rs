@@ -19,112 +19,745 @@ use std::collections::HashMap;
 /// Magic bytes for TENT payload
 const TENT_MAGIC: [u8; 4] = [0x54, 0x45, 0x4E, 0x54]; // "TENT"
 
+/// Current header format version
+const FORMAT_VERSION: u8 = 1;
+
+/// Header size: MAGIC (4) + VERSION (1) + LENGTH (4) + CRC-16 (2)
+const HEADER_LEN: usize = 11;
+
+/// Version 0 header: MAGIC (4) + LENGTH (4)
+const LEGACY_HEADER_LEN: usize = 8;
+
+/// Version 0 XOR parity bytes trailing the payload (never checked)
+const LEGACY_PARITY: usize = 16;
+
+/// Header version that records the parity level chosen for the payload
+const ADAPTIVE_FORMAT_VERSION: u8 = 2;
+
+/// Version 2 header: version 1 header + PARITY (1)
+const ADAPTIVE_HEADER_LEN: usize = 12;
+
+/// Header version that carries an HMAC-SHA256 tag over the payload
+const SIGNED_FORMAT_VERSION: u8 = 3;
+
+/// Version 3 header: version 2 header + TAG (32)
+const SIGNED_HEADER_LEN: usize = 44;
+
+/// HMAC-SHA256 tag size
+const TAG_LEN: usize = 32;
+
 /// Reed-Solomon parity bytes
 const RS_PARITY: usize = 16;
 
-/// Bits per channel for LSB extraction
+/// Upper bound on adaptive parity (corrects 64 errors per codeword)
+const MAX_RS_PARITY: usize = 128;
+
+/// Default bits per channel for LSB extraction
 const BITS_PER_CHANNEL: u8 = 2;
 
+/// Seed for the pixel walk ("TENT")
+const WALK_SEED: u64 = 0x54454E54;
+
+/// Multi-frame sub-header: CHUNK_INDEX (2) + TOTAL_CHUNKS (2)
+const CHUNK_HEADER_LEN: usize = 4;
+
+/// TLV record header: TAG (1) + LENGTH (4)
+const TLV_HEADER_LEN: usize = 5;
+
 // ============================================================================
 // PRIME WALK GENERATOR
 // ============================================================================
 
+/// Source of pixel positions for the spread-spectrum walk
+///
+/// Injection and extraction clone the carrier's source and `reset` it to
+/// the same seed, so any deterministic generator (e.g. a ChaCha-based
+/// CSPRNG) can replace `PrimeWalk`.
+pub trait WalkSource: Clone {
+    /// Next position in `0..max`
+    fn next(&mut self, max: usize) -> usize;
+
+    /// Restart the sequence from `seed`
+    fn reset(&mut self, seed: u64);
+}
+
 /// Generates a pseudo-random walk seeded by prime numbers
 /// This ensures the same "path" is used for encoding and decoding
+#[derive(Clone)]
 pub struct PrimeWalk {
     primes: Vec<u64>,
-    index: usize,
+    increment: u64,
     state: u64,
 }
 
+/// LCG multiplier (Knuth MMIX); ≡ 1 mod 4 for a full 2⁶⁴ period
+const WALK_MULTIPLIER: u64 = 6364136223846793005;
+
 impl PrimeWalk {
     pub fn new(seed: u64) -> Self {
         // First 20 primes as the basis
         let primes = vec![
             2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71,
         ];
+        Self::from_primes(seed, primes)
+    }
+
+    /// Walk driven by a custom prime table
+    pub fn with_primes(seed: u64, primes: Vec<u64>) -> Result<Self, &'static str> {
+        if primes.is_empty() {
+            return Err("Prime table must not be empty");
+        }
+        Ok(Self::from_primes(seed, primes))
+    }
+
+    fn from_primes(seed: u64, primes: Vec<u64>) -> Self {
+        // Fold the table into an odd increment so the accumulator keeps full period
+        let increment = primes
+            .iter()
+            .fold(0u64, |acc, &p| acc.wrapping_mul(WALK_MULTIPLIER).wrapping_add(p))
+            | 1;
+
         PrimeWalk {
             primes,
-            index: 0,
+            increment,
             state: seed,
         }
     }
 
+    /// The prime table driving this walk
+    pub fn primes(&self) -> &[u64] {
+        &self.primes
+    }
+
     /// Get next position in the pseudo-random walk
+    ///
+    /// The 64-bit accumulator advances before reduction, so the period does
+    /// not depend on `max`; for power-of-two `max` every position is visited
+    /// once per `max` steps.
     pub fn next(&mut self, max: usize) -> usize {
-        let prime = self.primes[self.index % self.primes.len()];
-        self.state = (self.state.wrapping_mul(prime).wrapping_add(prime)) % (max as u64);
-        self.index += 1;
-        self.state as usize
+        self.state = self
+            .state
+            .wrapping_mul(WALK_MULTIPLIER)
+            .wrapping_add(self.increment);
+        (self.state % max as u64) as usize
     }
 
     /// Reset the walk
     pub fn reset(&mut self, seed: u64) {
         self.state = seed;
-        self.index = 0;
+    }
+}
+
+impl WalkSource for PrimeWalk {
+    fn next(&mut self, max: usize) -> usize {
+        PrimeWalk::next(self, max)
+    }
+
+    fn reset(&mut self, seed: u64) {
+        PrimeWalk::reset(self, seed)
+    }
+}
+
+/// Pixel visiting order for the spread-spectrum walk
+/// Yields every pixel once; revisited pixels are skipped by probing forward.
+/// Keeps one bit of bookkeeping per pixel, never the pixels themselves.
+struct PixelWalk<W> {
+    walk: W,
+    used: Vec<u64>,
+    total: usize,
+    visited: usize,
+}
+
+impl<W: WalkSource> PixelWalk<W> {
+    fn new(mut walk: W, total: usize) -> Self {
+        walk.reset(WALK_SEED);
+        PixelWalk {
+            walk,
+            used: vec![0; total.div_ceil(64)],
+            total,
+            visited: 0,
+        }
+    }
+
+    /// Pixels not yet visited
+    fn remaining(&self) -> usize {
+        self.total - self.visited
+    }
+}
+
+impl<W: WalkSource> Iterator for PixelWalk<W> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.visited == self.total {
+            return None;
+        }
+
+        let mut pixel = self.walk.next(self.total);
+        while self.used[pixel / 64] & (1 << (pixel % 64)) != 0 {
+            pixel = (pixel + 1) % self.total;
+        }
+        self.used[pixel / 64] |= 1 << (pixel % 64);
+        self.visited += 1;
+        Some(pixel)
     }
 }
 
 // ============================================================================
-// REED-SOLOMON ERROR CORRECTION (Simplified)
+// REED-SOLOMON ERROR CORRECTION
 // ============================================================================
 
-/// Simplified Reed-Solomon encoder/decoder
-/// In production, use a proper RS library
+/// Largest Reed-Solomon codeword over GF(256)
+const RS_BLOCK: usize = 255;
+
+/// GF(256) arithmetic (primitive polynomial x⁸ + x⁴ + x³ + x² + 1)
+struct Gf256 {
+    exp: [u8; 512],
+    log: [u8; 256],
+}
+
+impl Gf256 {
+    fn new() -> Self {
+        let mut exp = [0u8; 512];
+        let mut log = [0u8; 256];
+        let mut x: u16 = 1;
+
+        for (i, slot) in exp.iter_mut().take(255).enumerate() {
+            *slot = x as u8;
+            log[x as usize] = i as u8;
+            x <<= 1;
+            if x & 0x100 != 0 {
+                x ^= 0x11d;
+            }
+        }
+        for i in 255..512 {
+            exp[i] = exp[i - 255];
+        }
+
+        Gf256 { exp, log }
+    }
+
+    fn mul(&self, a: u8, b: u8) -> u8 {
+        if a == 0 || b == 0 {
+            return 0;
+        }
+        self.exp[self.log[a as usize] as usize + self.log[b as usize] as usize]
+    }
+
+    fn inv(&self, a: u8) -> u8 {
+        self.exp[255 - self.log[a as usize] as usize]
+    }
+
+    /// α^n for the generator α = 2
+    fn alpha_pow(&self, n: usize) -> u8 {
+        self.exp[n % 255]
+    }
+}
+
+/// Encoded size of `data_len` bytes with `parity` check bytes per codeword
+fn encoded_len_with(data_len: usize, parity: usize) -> usize {
+    let blocks = data_len.div_ceil(RS_BLOCK - parity).max(1);
+    data_len + blocks * parity
+}
+
+/// Reed-Solomon encoder/decoder over GF(256)
+///
+/// Data is split into blocks of up to `255 - parity` bytes, each followed
+/// by `parity` check bytes. Each block corrects up to `parity / 2` byte errors.
 pub struct ReedSolomon {
     parity_bytes: usize,
+    gf: Gf256,
+    generator: Vec<u8>,
 }
 
 impl ReedSolomon {
     pub fn new(parity: usize) -> Self {
+        let gf = Gf256::new();
+
+        // g(x) = (x - α⁰)(x - α¹)...(x - α^(parity-1)), highest degree first
+        let mut generator = vec![1u8];
+        for i in 0..parity {
+            let root = gf.alpha_pow(i);
+            let mut next = vec![0u8; generator.len() + 1];
+            for (j, &coef) in generator.iter().enumerate() {
+                next[j] ^= coef;
+                next[j + 1] ^= gf.mul(coef, root);
+            }
+            generator = next;
+        }
+
         ReedSolomon {
             parity_bytes: parity,
+            gf,
+            generator,
         }
     }
 
-    /// Add parity bytes (simplified: just append XOR checksum)
+    /// Data bytes carried by one codeword
+    fn block_data(&self) -> usize {
+        RS_BLOCK - self.parity_bytes
+    }
+
+    /// Encoded size of `data_len` bytes of data
+    pub fn encoded_len(&self, data_len: usize) -> usize {
+        encoded_len_with(data_len, self.parity_bytes)
+    }
+
+    /// Append parity bytes to each block of data
     pub fn encode(&self, data: &[u8]) -> Vec<u8> {
-        let mut encoded = data.to_vec();
+        let mut encoded = Vec::with_capacity(self.encoded_len(data.len()));
 
-        // Generate parity bytes (simplified XOR-based)
-        for i in 0..self.parity_bytes {
-            let mut parity: u8 = 0;
-            for (j, &byte) in data.iter().enumerate() {
-                parity ^= byte.rotate_left((i as u32 + j as u32) % 8);
-            }
-            encoded.push(parity);
+        if data.is_empty() {
+            encoded.extend(self.encode_block(&[]));
+            return encoded;
+        }
+
+        for block in data.chunks(self.block_data()) {
+            encoded.extend(block);
+            encoded.extend(self.encode_block(block));
         }
 
         encoded
     }
 
-    /// Attempt to correct errors (simplified)
+    /// Parity for one block: remainder of data·x^parity divided by g(x)
+    fn encode_block(&self, block: &[u8]) -> Vec<u8> {
+        let mut remainder = vec![0u8; self.parity_bytes];
+
+        for &byte in block {
+            let coef = byte ^ remainder[0];
+            remainder.rotate_left(1);
+            remainder[self.parity_bytes - 1] = 0;
+            if coef != 0 {
+                for (r, &g) in remainder.iter_mut().zip(&self.generator[1..]) {
+                    *r ^= self.gf.mul(g, coef);
+                }
+            }
+        }
+
+        remainder
+    }
+
+    /// Correct errors and strip parity
+    /// Fails only when a block holds more errors than it can correct
     pub fn decode(&self, data: &[u8]) -> Result<Vec<u8>, &'static str> {
         if data.len() < self.parity_bytes {
             return Err("Data too short");
         }
 
-        let payload_len = data.len() - self.parity_bytes;
-        let payload = &data[..payload_len];
-        let _parity = &data[payload_len..];
+        let mut decoded = Vec::with_capacity(data.len());
+
+        for block in data.chunks(RS_BLOCK) {
+            if block.len() < self.parity_bytes {
+                return Err("Data too short");
+            }
+            let corrected = self.decode_block(block)?;
+            decoded.extend(&corrected[..block.len() - self.parity_bytes]);
+        }
+
+        Ok(decoded)
+    }
+
+    /// Evaluate a codeword (highest degree first) at x
+    fn eval(&self, poly: &[u8], x: u8) -> u8 {
+        poly.iter().fold(0, |acc, &coef| self.gf.mul(acc, x) ^ coef)
+    }
+
+    /// Berlekamp-Massey, Chien search, then solve for error magnitudes
+    fn decode_block(&self, block: &[u8]) -> Result<Vec<u8>, &'static str> {
+        let gf = &self.gf;
+        let n = block.len();
+        let syndromes: Vec<u8> = (0..self.parity_bytes)
+            .map(|j| self.eval(block, gf.alpha_pow(j)))
+            .collect();
+
+        if syndromes.iter().all(|&s| s == 0) {
+            return Ok(block.to_vec());
+        }
+
+        // Error locator Λ(x), lowest degree first
+        let mut locator = vec![1u8];
+        let mut previous = vec![1u8];
+        let mut errors = 0;
+        let mut shift = 1;
+        let mut last_discrepancy = 1u8;
+
+        for k in 0..self.parity_bytes {
+            let mut discrepancy = syndromes[k];
+            for i in 1..=errors.min(locator.len() - 1) {
+                discrepancy ^= gf.mul(locator[i], syndromes[k - i]);
+            }
+
+            if discrepancy == 0 {
+                shift += 1;
+                continue;
+            }
+
+            let scale = gf.mul(discrepancy, gf.inv(last_discrepancy));
+            let mut updated = locator.clone();
+            if updated.len() < previous.len() + shift {
+                updated.resize(previous.len() + shift, 0);
+            }
+            for (i, &b) in previous.iter().enumerate() {
+                updated[i + shift] ^= gf.mul(scale, b);
+            }
+
+            if 2 * errors <= k {
+                previous = locator;
+                errors = k + 1 - errors;
+                last_discrepancy = discrepancy;
+                shift = 1;
+            } else {
+                shift += 1;
+            }
+            locator = updated;
+        }
+
+        if 2 * errors > self.parity_bytes {
+            return Err("Too many errors to correct");
+        }
+
+        // Chien search: position p has locator X = α^(n-1-p), root at X⁻¹
+        let mut positions = Vec::with_capacity(errors);
+        let mut locators = Vec::with_capacity(errors);
+        for p in 0..n {
+            let x = gf.alpha_pow(n - 1 - p);
+            let x_inv = gf.inv(x);
+            let value = locator
+                .iter()
+                .rev()
+                .fold(0, |acc, &coef| gf.mul(acc, x_inv) ^ coef);
+            if value == 0 {
+                positions.push(p);
+                locators.push(x);
+            }
+        }
+
+        if positions.len() != errors {
+            return Err("Too many errors to correct");
+        }
+
+        // Magnitudes: solve Σ Y_k · X_k^j = S_j for j < errors
+        let mut system: Vec<Vec<u8>> = (0..errors)
+            .map(|j| {
+                let mut row: Vec<u8> = locators
+                    .iter()
+                    .map(|&x| gf.alpha_pow(gf.log[x as usize] as usize * j))
+                    .collect();
+                row.push(syndromes[j]);
+                row
+            })
+            .collect();
+
+        for col in 0..errors {
+            let pivot = (col..errors)
+                .find(|&r| system[r][col] != 0)
+                .ok_or("Too many errors to correct")?;
+            system.swap(col, pivot);
+
+            let inv = gf.inv(system[col][col]);
+            for v in system[col].iter_mut() {
+                *v = gf.mul(*v, inv);
+            }
+
+            let pivot_row = system[col].clone();
+            for (r, row) in system.iter_mut().enumerate() {
+                if r != col && row[col] != 0 {
+                    let factor = row[col];
+                    for (v, &p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                        *v ^= gf.mul(factor, p);
+                    }
+                }
+            }
+        }
+
+        let mut corrected = block.to_vec();
+        for (k, &p) in positions.iter().enumerate() {
+            corrected[p] ^= system[k][errors];
+        }
+
+        let clean = (0..self.parity_bytes).all(|j| self.eval(&corrected, gf.alpha_pow(j)) == 0);
+        if !clean {
+            return Err("Too many errors to correct");
+        }
+
+        Ok(corrected)
+    }
+}
+
+// ============================================================================
+// CHECKSUM
+// ============================================================================
+
+/// CRC-16/CCITT-FALSE (poly 0x1021, init 0xFFFF)
+fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+// ============================================================================
+// AUTHENTICATION (HMAC-SHA256)
+// ============================================================================
+
+/// SHA-256 input block size
+const SHA256_BLOCK: usize = 64;
+
+/// SHA-256 round constants
+const SHA256_K: [u32; 64] = [
+    0x428A2F98, 0x71374491, 0xB5C0FBCF, 0xE9B5DBA5, 0x3956C25B, 0x59F111F1, 0x923F82A4, 0xAB1C5ED5,
+    0xD807AA98, 0x12835B01, 0x243185BE, 0x550C7DC3, 0x72BE5D74, 0x80DEB1FE, 0x9BDC06A7, 0xC19BF174,
+    0xE49B69C1, 0xEFBE4786, 0x0FC19DC6, 0x240CA1CC, 0x2DE92C6F, 0x4A7484AA, 0x5CB0A9DC, 0x76F988DA,
+    0x983E5152, 0xA831C66D, 0xB00327C8, 0xBF597FC7, 0xC6E00BF3, 0xD5A79147, 0x06CA6351, 0x14292967,
+    0x27B70A85, 0x2E1B2138, 0x4D2C6DFC, 0x53380D13, 0x650A7354, 0x766A0ABB, 0x81C2C92E, 0x92722C85,
+    0xA2BFE8A1, 0xA81A664B, 0xC24B8B70, 0xC76C51A3, 0xD192E819, 0xD6990624, 0xF40E3585, 0x106AA070,
+    0x19A4C116, 0x1E376C08, 0x2748774C, 0x34B0BCB5, 0x391C0CB3, 0x4ED8AA4A, 0x5B9CCA4F, 0x682E6FF3,
+    0x748F82EE, 0x78A5636F, 0x84C87814, 0x8CC70208, 0x90BEFFFA, 0xA4506CEB, 0xBEF9A3F7, 0xC67178F2,
+];
+
+/// SHA-256 initial hash value
+const SHA256_INIT: [u32; 8] = [
+    0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19,
+];
+
+/// SHA-256 (FIPS 180-4)
+fn sha256(data: &[u8]) -> [u8; 32] {
+    // Pad with 0x80, zeros, then the bit length, to a whole number of blocks
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % SHA256_BLOCK != SHA256_BLOCK - 8 {
+        message.push(0);
+    }
+    message.extend(&(data.len() as u64 * 8).to_be_bytes());
+
+    let mut state = SHA256_INIT;
+    for block in message.chunks_exact(SHA256_BLOCK) {
+        let mut schedule = [0u32; 64];
+        for (word, bytes) in schedule.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        for i in 16..64 {
+            let (w15, w2) = (schedule[i - 15], schedule[i - 2]);
+            let s0 = w15.rotate_right(7) ^ w15.rotate_right(18) ^ (w15 >> 3);
+            let s1 = w2.rotate_right(17) ^ w2.rotate_right(19) ^ (w2 >> 10);
+            schedule[i] = schedule[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(schedule[i - 7])
+                .wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+        for (&k, &w) in SHA256_K.iter().zip(&schedule) {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(k).wrapping_add(w);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+
+        for (word, add) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *word = word.wrapping_add(add);
+        }
+    }
 
-        // In production: use parity to detect and correct errors
-        // For now: trust the data if parity exists
+    let mut digest = [0u8; 32];
+    for (bytes, word) in digest.chunks_exact_mut(4).zip(state) {
+        bytes.copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
 
-        Ok(payload.to_vec())
+/// HMAC-SHA256 (RFC 2104); keys longer than a block are hashed first
+fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; TAG_LEN] {
+    let mut block_key = [0u8; SHA256_BLOCK];
+    if key.len() > SHA256_BLOCK {
+        block_key[..32].copy_from_slice(&sha256(key));
+    } else {
+        block_key[..key.len()].copy_from_slice(key);
     }
+
+    let mut inner: Vec<u8> = block_key.iter().map(|b| b ^ 0x36).collect();
+    inner.extend(message);
+    let mut outer: Vec<u8> = block_key.iter().map(|b| b ^ 0x5C).collect();
+    outer.extend(sha256(&inner));
+    sha256(&outer)
+}
+
+/// Compare tags without exiting early, so timing does not reveal how many
+/// leading bytes matched
+fn tags_match(a: &[u8; TAG_LEN], b: &[u8; TAG_LEN]) -> bool {
+    a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
 }
 
 // ============================================================================
 // OPTICAL CARRIER (The Visual Codec)
 // ============================================================================
 
-pub struct OpticalCarrier {
+/// Color channel that carries the payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Channel {
+    Red,
+    Green,
+    Blue,
+    Alpha,
+}
+
+impl Channel {
+    /// Byte offset of this channel within an RGBA or RGB pixel
+    pub fn offset(&self) -> usize {
+        match self {
+            Channel::Red => 0,
+            Channel::Green => 1,
+            Channel::Blue => 2,
+            Channel::Alpha => 3,
+        }
+    }
+}
+
+/// Byte layout of the pixel buffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PixelFormat {
+    /// 4 bytes per pixel (e.g. canvas ImageData)
+    #[default]
+    Rgba,
+    /// 3 bytes per pixel (e.g. PNGs decoded without alpha)
+    Rgb,
+}
+
+impl PixelFormat {
+    /// Bytes per pixel
+    pub fn stride(&self) -> usize {
+        match self {
+            PixelFormat::Rgba => 4,
+            PixelFormat::Rgb => 3,
+        }
+    }
+
+    /// Whether pixels of this format have the given channel
+    pub fn has_channel(&self, channel: Channel) -> bool {
+        channel.offset() < self.stride()
+    }
+}
+
+/// Order in which a byte's bit chunks are spread over channel samples
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BitOrder {
+    /// Most significant chunk first (the original layout)
+    #[default]
+    MsbFirst,
+    /// Least significant chunk first
+    LsbFirst,
+}
+
+/// Which channel carries the payload and how many LSBs it uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CarrierConfig {
+    pub channel: Channel,
+    /// Low bits used per channel sample: 1, 2, or 4
+    pub bits_per_channel: u8,
+}
+
+impl Default for CarrierConfig {
+    fn default() -> Self {
+        CarrierConfig {
+            channel: Channel::Blue,
+            bits_per_channel: BITS_PER_CHANNEL,
+        }
+    }
+}
+
+impl CarrierConfig {
+    /// Check that the bit depth divides a byte evenly
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self.bits_per_channel {
+            1 | 2 | 4 => Ok(()),
+            _ => Err("bits_per_channel must be 1, 2, or 4"),
+        }
+    }
+}
+
+/// Result of `OpticalCarrier::detect_payload`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PayloadProbe {
+    /// Bit offset of the TENT magic in the extracted stream, if found
+    pub bit_offset: Option<usize>,
+    /// Encoded (RS-protected) length the header declares; `None` if the
+    /// header is truncated or of an unsupported version
+    pub declared_length: Option<usize>,
+}
+
+impl PayloadProbe {
+    /// Whether the image appears to already carry a payload
+    pub fn is_present(&self) -> bool {
+        self.bit_offset.is_some()
+    }
+}
+
+/// How far an injection moved the pixels away from the original
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InjectionStats {
+    /// Bytes that differ from the original
+    pub modified_bytes: usize,
+    /// Mean absolute change per channel, in RGBA order
+    pub mean_abs_change: [f64; 4],
+    /// Peak signal-to-noise ratio in dB (infinite when unchanged)
+    pub psnr: f64,
+}
+
+pub struct OpticalCarrier<W: WalkSource = PrimeWalk> {
     width: u32,
     height: u32,
     pixel_data: Vec<u8>,
-    prime_walk: PrimeWalk,
+    walk: W,
     rs: ReedSolomon,
+    config: CarrierConfig,
+    format: PixelFormat,
+    bit_order: BitOrder,
+    max_parity: usize,
+}
+
+/// A parsed payload header
+struct PayloadHeader {
+    /// Header size in bytes, magic included
+    len: usize,
+    /// Encoded (RS-protected) payload length
+    encoded_len: usize,
+    crc: Option<u16>,
+    /// Parity bytes per codeword
+    parity: usize,
+    /// HMAC-SHA256 over the payload, for signed payloads
+    tag: Option<[u8; TAG_LEN]>,
+}
+
+impl PayloadHeader {
+    /// Full header size for a version byte, or `None` if unsupported
+    fn len_for(version: u8) -> Option<usize> {
+        match version {
+            FORMAT_VERSION => Some(HEADER_LEN),
+            ADAPTIVE_FORMAT_VERSION => Some(ADAPTIVE_HEADER_LEN),
+            SIGNED_FORMAT_VERSION => Some(SIGNED_HEADER_LEN),
+            _ => None,
+        }
+    }
 }
 
 impl OpticalCarrier {
@@ -133,39 +766,191 @@ impl OpticalCarrier {
             width,
             height,
             pixel_data: vec![0; (width * height * 4) as usize],
-            prime_walk: PrimeWalk::new(0x54454E54), // "TENT" as seed
+            walk: PrimeWalk::new(WALK_SEED),
+            rs: ReedSolomon::new(RS_PARITY),
+            config: CarrierConfig::default(),
+            format: PixelFormat::Rgba,
+            bit_order: BitOrder::MsbFirst,
+            max_parity: RS_PARITY,
+        }
+    }
+
+    /// Create a carrier using a specific channel and bit depth
+    pub fn with_config(
+        width: u32,
+        height: u32,
+        config: CarrierConfig,
+    ) -> Result<Self, &'static str> {
+        Self::with_format(width, height, PixelFormat::Rgba, config)
+    }
+
+    /// Create a carrier over a buffer with the given pixel layout
+    pub fn with_format(
+        width: u32,
+        height: u32,
+        format: PixelFormat,
+        config: CarrierConfig,
+    ) -> Result<Self, &'static str> {
+        let mut carrier = Self::streaming(width, height, format, config)?;
+        carrier.pixel_data = vec![0; width as usize * height as usize * format.stride()];
+        Ok(carrier)
+    }
+
+    /// Create a carrier without a pixel buffer, for `extract_payload_tiled`
+    /// on images too large to hold in memory
+    pub fn streaming(
+        width: u32,
+        height: u32,
+        format: PixelFormat,
+        config: CarrierConfig,
+    ) -> Result<Self, &'static str> {
+        config.validate()?;
+        if !format.has_channel(config.channel) {
+            return Err("Pixel format has no such channel");
+        }
+        Ok(OpticalCarrier {
+            width,
+            height,
+            pixel_data: Vec::new(),
+            walk: PrimeWalk::new(WALK_SEED),
             rs: ReedSolomon::new(RS_PARITY),
+            config,
+            format,
+            bit_order: BitOrder::MsbFirst,
+            max_parity: RS_PARITY,
+        })
+    }
+}
+
+impl<W: WalkSource> OpticalCarrier<W> {
+    /// Swap the pixel walk, keeping pixels, configuration and format
+    pub fn with_walk_source<V: WalkSource>(self, walk: V) -> OpticalCarrier<V> {
+        OpticalCarrier {
+            width: self.width,
+            height: self.height,
+            pixel_data: self.pixel_data,
+            walk,
+            rs: self.rs,
+            config: self.config,
+            format: self.format,
+            bit_order: self.bit_order,
+            max_parity: self.max_parity,
+        }
+    }
+
+    /// Use the given chunk order when reading and writing payload bytes
+    /// `MsbFirst` (the default) matches payloads from earlier versions.
+    pub fn with_bit_order(mut self, bit_order: BitOrder) -> Self {
+        self.bit_order = bit_order;
+        self
+    }
+
+    /// Get the chunk order of payload bytes
+    pub fn bit_order(&self) -> BitOrder {
+        self.bit_order
+    }
+
+    /// Spend spare image capacity on up to `max_parity` RS parity bytes per
+    /// codeword (clamped to 16..=128)
+    /// Above 16 the chosen level is recorded in a version 2 header, which
+    /// extraction reads back; at 16 (the default) the version 1 layout is
+    /// written unchanged.
+    pub fn with_max_parity(mut self, max_parity: usize) -> Self {
+        self.max_parity = max_parity.clamp(RS_PARITY, MAX_RS_PARITY);
+        self
+    }
+
+    /// Get the adaptive parity ceiling
+    pub fn max_parity(&self) -> usize {
+        self.max_parity
+    }
+
+    /// Parity bytes per codeword `inject_payload` would use for a payload of
+    /// `payload_len` bytes: the most that fits, up to `max_parity`
+    pub fn parity_for(&self, payload_len: usize) -> usize {
+        self.parity_within(payload_len, self.header_len())
+    }
+
+    /// `parity_for` behind a header of `header_len` bytes
+    fn parity_within(&self, payload_len: usize, header_len: usize) -> usize {
+        let spare = self.carrier_bytes().saturating_sub(header_len);
+        (RS_PARITY..=self.max_parity)
+            .rev()
+            .find(|&parity| encoded_len_with(payload_len, parity) <= spare)
+            .unwrap_or(RS_PARITY)
+    }
+
+    /// Whether injection writes the version 2 header
+    fn is_adaptive(&self) -> bool {
+        self.max_parity > RS_PARITY
+    }
+
+    /// Header size `inject_payload` writes
+    fn header_len(&self) -> usize {
+        if self.is_adaptive() {
+            ADAPTIVE_HEADER_LEN
+        } else {
+            HEADER_LEN
         }
     }
 
+    /// Get the channel/bit-depth configuration
+    pub fn config(&self) -> CarrierConfig {
+        self.config
+    }
+
+    /// Get the pixel buffer layout
+    pub fn pixel_format(&self) -> PixelFormat {
+        self.format
+    }
+
     /// Ingest raw RGBA pixel data from canvas
     pub fn ingest_frame(&mut self, data: &[u8]) {
         self.pixel_data = data.to_vec();
     }
 
-    /// Extract bits from LSB of Blue channel
-    fn extract_blue_lsb(&self) -> Vec<u8> {
-        let mut bits = Vec::new();
-        let mask = (1 << BITS_PER_CHANNEL) - 1;
+    /// Number of whole pixels in the buffer
+    fn pixel_count(&self) -> usize {
+        self.pixel_data.len() / self.format.stride()
+    }
 
-        // Extract from Blue channel (index 2 in RGBA)
-        for i in (2..self.pixel_data.len()).step_by(4) {
-            let blue = self.pixel_data[i];
-            bits.push(blue & mask);
+    /// Reject buffers that end partway through a pixel
+    fn check_frame(&self) -> Result<(), &'static str> {
+        if !self.pixel_data.len().is_multiple_of(self.format.stride()) {
+            return Err("Frame length is not a multiple of the pixel stride");
         }
+        Ok(())
+    }
 
-        bits
+    /// Pixel visiting order for the spread-spectrum walk
+    /// The walk is reset to the same seed on every call, so injection and
+    /// extraction agree.
+    fn pixel_order(&self, count: usize) -> Vec<usize> {
+        PixelWalk::new(self.walk.clone(), self.pixel_count()).take(count).collect()
+    }
+
+    /// Extract bits from the LSBs of the configured channel, in walk order
+    fn extract_lsb(&self) -> Vec<u8> {
+        let mask = (1 << self.config.bits_per_channel) - 1;
+        let offset = self.config.channel.offset();
+        let stride = self.format.stride();
+
+        self.pixel_order(self.pixel_count())
+            .into_iter()
+            .map(|pixel| self.pixel_data[pixel * stride + offset] & mask)
+            .collect()
     }
 
     /// Convert extracted bits to bytes
     fn bits_to_bytes(&self, bits: &[u8]) -> Vec<u8> {
         let mut bytes = Vec::new();
-        let bits_per_byte = 8 / BITS_PER_CHANNEL as usize;
+        let depth = self.config.bits_per_channel;
+        let bits_per_byte = 8 / depth as usize;
 
         for chunk in bits.chunks(bits_per_byte) {
             let mut byte: u8 = 0;
             for (i, &b) in chunk.iter().enumerate() {
-                byte |= b << (BITS_PER_CHANNEL * (bits_per_byte - 1 - i) as u8);
+                byte |= b << (depth * self.chunk_position(i, bits_per_byte));
             }
             bytes.push(byte);
         }
@@ -183,10 +968,60 @@ impl OpticalCarrier {
         None
     }
 
+    /// Look for a payload header without decoding or error-correcting
+    /// A cheap precheck before injecting; a found magic is not proof that
+    /// the payload itself is intact.
+    pub fn detect_payload(&self) -> PayloadProbe {
+        let absent = PayloadProbe { bit_offset: None, declared_length: None };
+        if self.check_frame().is_err() {
+            return absent;
+        }
+
+        let raw_bytes = self.bits_to_bytes(&self.extract_lsb());
+        let Some(magic_pos) = self.find_magic(&raw_bytes) else {
+            return absent;
+        };
+
+        PayloadProbe {
+            bit_offset: Some(magic_pos * 8),
+            declared_length: Self::parse_header(&raw_bytes[magic_pos..])
+                .ok()
+                .map(|header| header.encoded_len),
+        }
+    }
+
     /// Extract the payload from the image
+    /// A signed payload is returned without checking its tag; use
+    /// `extract_payload_verified` to authenticate it.
     pub fn extract_payload(&self) -> Result<Vec<u8>, &'static str> {
-        // Step 1: Extract LSB bits from Blue channel
-        let bits = self.extract_blue_lsb();
+        self.extract_with_header().map(|(payload, _)| payload)
+    }
+
+    /// Extract a payload written by `inject_payload_signed` and check its
+    /// HMAC-SHA256 tag against `key`
+    /// Reed-Solomon still repairs noise first, so only edits that survive
+    /// error correction (deliberate re-encoding) fail the check.
+    pub fn extract_payload_verified(&self, key: &[u8]) -> Result<Vec<u8>, &'static str> {
+        let (payload, header) = self.extract_with_header()?;
+        let tag = header.tag.ok_or("Payload is not signed")?;
+        if !tags_match(&tag, &hmac_sha256(key, &payload)) {
+            return Err("bad signature");
+        }
+        Ok(payload)
+    }
+
+    /// Extract the payload along with the header it was found under
+    /// Falls back to the version 0 layout when nothing decodes along the walk.
+    fn extract_with_header(&self) -> Result<(Vec<u8>, PayloadHeader), &'static str> {
+        self.check_frame()?;
+        self.extract_walk().or_else(|err| self.extract_legacy().map_err(|_| err))
+    }
+
+    /// Extract a versioned payload written along the prime walk
+    fn extract_walk(&self) -> Result<(Vec<u8>, PayloadHeader), &'static str> {
+
+        // Step 1: Extract LSB bits from the carrier channel
+        let bits = self.extract_lsb();
 
         // Step 2: Convert to bytes
         let raw_bytes = self.bits_to_bytes(&bits);
@@ -194,83 +1029,371 @@ impl OpticalCarrier {
         // Step 3: Find TENT magic marker
         let magic_pos = self.find_magic(&raw_bytes).ok_or("No TENT payload found")?;
 
-        // Step 4: Read length (4 bytes after magic)
-        if magic_pos + 8 > raw_bytes.len() {
-            return Err("Truncated header");
-        }
-
-        let length = u32::from_be_bytes([
-            raw_bytes[magic_pos + 4],
-            raw_bytes[magic_pos + 5],
-            raw_bytes[magic_pos + 6],
-            raw_bytes[magic_pos + 7],
-        ]) as usize;
+        // Step 4: Read version, length, checksum and parity level
+        let header = Self::parse_header(&raw_bytes[magic_pos..])?;
 
         // Step 5: Extract payload
-        let payload_start = magic_pos + 8;
-        let payload_end = payload_start + length;
+        let payload_start = magic_pos + header.len;
+        let payload_end = payload_start.saturating_add(header.encoded_len);
 
         if payload_end > raw_bytes.len() {
             return Err("Payload extends beyond image");
         }
 
-        let encoded_payload = &raw_bytes[payload_start..payload_end];
-
-        // Step 6: Apply Reed-Solomon error correction
-        let clean_payload = self.rs.decode(encoded_payload)?;
-
-        Ok(clean_payload)
+        // Steps 6-7: Reed-Solomon correction and checksum
+        let payload = self.decode_payload(&raw_bytes[payload_start..payload_end], &header)?;
+        Ok((payload, header))
     }
 
-    /// Spread Spectrum Encoder: Inject payload into image
-    pub fn inject_payload(&mut self, payload: &[u8]) -> Result<(), &'static str> {
-        // Step 1: Apply Reed-Solomon encoding
-        let encoded = self.rs.encode(payload);
+    /// Extract a version 0 payload
+    /// Version 0 predates the prime walk and Reed-Solomon: MAGIC + LENGTH +
+    /// payload + XOR parity, 2 bits MSB first into every pixel's Blue LSBs
+    /// from the top-left. LENGTH counts the parity, which is stripped
+    /// unchecked as the original decoder did.
+    fn extract_legacy(&self) -> Result<(Vec<u8>, PayloadHeader), &'static str> {
+        let stride = self.format.stride();
+        let raw_bytes: Vec<u8> = self.pixel_data[Channel::Blue.offset()..]
+            .iter()
+            .step_by(stride)
+            .map(|blue| blue & 0b11)
+            .collect::<Vec<_>>()
+            .chunks(4)
+            .map(|bits| bits.iter().fold(0u8, |byte, &bit| (byte << 2) | bit))
+            .collect();
 
-        // Step 2: Build header: MAGIC (4) + LENGTH (4) + PAYLOAD
-        let mut full_payload = TENT_MAGIC.to_vec();
-        full_payload.extend(&(encoded.len() as u32).to_be_bytes());
-        full_payload.extend(&encoded);
+        let magic_pos = self.find_magic(&raw_bytes).ok_or("No TENT payload found")?;
+        let header = raw_bytes
+            .get(magic_pos..magic_pos + LEGACY_HEADER_LEN)
+            .ok_or("Truncated header")?;
+        let encoded_len =
+            u32::from_be_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        if encoded_len < LEGACY_PARITY {
+            return Err("Invalid payload length");
+        }
 
-        // Step 3: Convert to bits
-        let bits = self.bytes_to_bits(&full_payload);
+        let payload_start = magic_pos + LEGACY_HEADER_LEN;
+        let encoded = raw_bytes
+            .get(payload_start..payload_start.saturating_add(encoded_len))
+            .ok_or("Payload extends beyond image")?;
+
+        let header = PayloadHeader {
+            len: LEGACY_HEADER_LEN,
+            encoded_len,
+            crc: None,
+            parity: LEGACY_PARITY,
+            tag: None,
+        };
+        Ok((encoded[..encoded_len - LEGACY_PARITY].to_vec(), header))
+    }
 
-        // Step 4: Inject into Blue channel LSB
-        let mask = !((1u8 << BITS_PER_CHANNEL) - 1);
-        let mut bit_idx = 0;
+    /// Parse a versioned header starting at the magic
+    fn parse_header(header: &[u8]) -> Result<PayloadHeader, &'static str> {
+        if header.len() < HEADER_LEN {
+            return Err("Truncated header");
+        }
 
-        for i in (2..self.pixel_data.len()).step_by(4) {
-            if bit_idx >= bits.len() {
-                break;
-            }
+        let full_len = PayloadHeader::len_for(header[4]).ok_or("Unsupported payload version")?;
+        if header.len() < full_len {
+            return Err("Truncated header");
+        }
 
-            // Clear LSB and inject
-            self.pixel_data[i] = (self.pixel_data[i] & mask) | bits[bit_idx];
-            bit_idx += 1;
+        let encoded_len =
+            u32::from_be_bytes([header[5], header[6], header[7], header[8]]) as usize;
+        let crc = Some(u16::from_be_bytes([header[9], header[10]]));
+        let parity = if full_len >= ADAPTIVE_HEADER_LEN { header[11] as usize } else { RS_PARITY };
+        if parity == 0 || parity >= RS_BLOCK {
+            return Err("Invalid parity level");
+        }
+        let tag = (full_len == SIGNED_HEADER_LEN).then(|| {
+            let mut tag = [0u8; TAG_LEN];
+            tag.copy_from_slice(&header[ADAPTIVE_HEADER_LEN..SIGNED_HEADER_LEN]);
+            tag
+        });
+
+        Ok(PayloadHeader { len: full_len, encoded_len, crc, parity, tag })
+    }
+
+    /// Apply Reed-Solomon error correction at the header's parity level,
+    /// then verify the checksum
+    fn decode_payload(
+        &self,
+        encoded: &[u8],
+        header: &PayloadHeader,
+    ) -> Result<Vec<u8>, &'static str> {
+        let adaptive;
+        let rs = if header.parity == self.rs.parity_bytes {
+            &self.rs
+        } else {
+            adaptive = ReedSolomon::new(header.parity);
+            &adaptive
+        };
+        let clean_payload = rs.decode(encoded)?;
+
+        if header.crc.is_some_and(|crc| crc != crc16(&clean_payload)) {
+            return Err("CRC mismatch");
+        }
+
+        Ok(clean_payload)
+    }
+
+    /// Extract the payload while pulling pixels from `reader` in tiles of
+    /// `tile_rows` rows, so at most one tile is held in memory
+    /// Unlike `extract_payload` the header must sit at the start of the walk
+    /// (where `inject_payload` puts it); reading stops once the header's
+    /// length has been read. The carrier's own pixel buffer is ignored, and
+    /// version 0 payloads are not read.
+    pub fn extract_payload_tiled<R: TileReader>(
+        &self,
+        reader: &mut R,
+        tile_rows: u32,
+    ) -> Result<Vec<u8>, &'static str> {
+        if tile_rows == 0 {
+            return Err("Tile must span at least one row");
         }
 
-        if bit_idx < bits.len() {
+        let total = self.width as usize * self.height as usize;
+        let mut pixels = PixelWalk::new(self.walk.clone(), total);
+        let mut tiles = TileCursor { reader, tile_rows, tile: Vec::new(), loaded: None };
+
+        // Magic and version byte first, to learn the full header size
+        let mut header = self.read_tiled(&mut pixels, &mut tiles, TENT_MAGIC.len() + 1)?;
+        if header[..4] != TENT_MAGIC {
+            return Err("No TENT payload found");
+        }
+        let full_len = PayloadHeader::len_for(header[4]).ok_or("Unsupported payload version")?;
+        let rest = full_len - header.len();
+        header.extend(self.read_tiled(&mut pixels, &mut tiles, rest)?);
+
+        let header = Self::parse_header(&header)?;
+        let encoded = self.read_tiled(&mut pixels, &mut tiles, header.encoded_len)?;
+        self.decode_payload(&encoded, &header)
+    }
+
+    /// Read the next `count` bytes along the walk, loading each tile they
+    /// touch once
+    fn read_tiled<R: TileReader>(
+        &self,
+        pixels: &mut PixelWalk<W>,
+        tiles: &mut TileCursor<'_, R>,
+        count: usize,
+    ) -> Result<Vec<u8>, &'static str> {
+        let bits_needed = count.saturating_mul(8 / self.config.bits_per_channel as usize);
+        if bits_needed > pixels.remaining() {
+            return Err("Payload extends beyond image");
+        }
+
+        let order: Vec<usize> = pixels.by_ref().take(bits_needed).collect();
+        let mut slots: Vec<usize> = (0..order.len()).collect();
+        slots.sort_unstable_by_key(|&slot| order[slot]);
+
+        let mask = (1 << self.config.bits_per_channel) - 1;
+        let offset = self.config.channel.offset();
+        let stride = self.format.stride();
+        let tile_pixels = tiles.tile_rows as usize * self.width as usize;
+
+        let mut bits = vec![0; order.len()];
+        for slot in slots {
+            let pixel = order[slot];
+            let tile = self.load_tile(tiles, pixel / tile_pixels)?;
+            bits[slot] = tile[(pixel % tile_pixels) * stride + offset] & mask;
+        }
+
+        Ok(self.bits_to_bytes(&bits))
+    }
+
+    /// Pull tile `index` from the reader unless it is already loaded
+    fn load_tile<'t, R: TileReader>(
+        &self,
+        tiles: &'t mut TileCursor<'_, R>,
+        index: usize,
+    ) -> Result<&'t [u8], &'static str> {
+        if tiles.loaded != Some(index) {
+            let first_row = index as u32 * tiles.tile_rows;
+            let rows = tiles.tile_rows.min(self.height - first_row);
+            let len = rows as usize * self.width as usize * self.format.stride();
+            tiles.tile.resize(len, 0);
+            tiles.loaded = None;
+            tiles.reader.read_rows(first_row, rows, &mut tiles.tile)?;
+            tiles.loaded = Some(index);
+        }
+        Ok(&tiles.tile)
+    }
+
+    /// Spread Spectrum Encoder: Inject payload into image
+    pub fn inject_payload(&mut self, payload: &[u8]) -> Result<(), &'static str> {
+        self.inject_framed(payload, None)
+    }
+
+    /// Inject a payload with an HMAC-SHA256 tag keyed by `key` in its header,
+    /// for `extract_payload_verified`
+    /// The tag takes 32 bytes of capacity, see `signed_capacity_bytes`.
+    pub fn inject_payload_signed(
+        &mut self,
+        payload: &[u8],
+        key: &[u8],
+    ) -> Result<(), &'static str> {
+        self.inject_framed(payload, Some(hmac_sha256(key, payload)))
+    }
+
+    fn inject_framed(
+        &mut self,
+        payload: &[u8],
+        tag: Option<[u8; TAG_LEN]>,
+    ) -> Result<(), &'static str> {
+        let header_len = if tag.is_some() { SIGNED_HEADER_LEN } else { self.header_len() };
+
+        // Step 0: Refuse up front so a failed injection leaves pixels untouched
+        self.check_frame()?;
+        if payload.len() > self.capacity_within(header_len) {
             return Err("Image too small for payload");
         }
 
+        // Step 1: Apply Reed-Solomon encoding, with as much parity as fits
+        let parity = self.parity_within(payload.len(), header_len);
+        let adaptive;
+        let rs = if parity == self.rs.parity_bytes {
+            &self.rs
+        } else {
+            adaptive = ReedSolomon::new(parity);
+            &adaptive
+        };
+        let encoded = rs.encode(payload);
+
+        // Step 2: Build header: MAGIC (4) + VERSION (1) + LENGTH (4) + CRC (2)
+        // [+ PARITY (1) [+ TAG (32)]] + PAYLOAD
+        let mut full_payload = TENT_MAGIC.to_vec();
+        let version = match header_len {
+            SIGNED_HEADER_LEN => SIGNED_FORMAT_VERSION,
+            ADAPTIVE_HEADER_LEN => ADAPTIVE_FORMAT_VERSION,
+            _ => FORMAT_VERSION,
+        };
+        full_payload.push(version);
+        full_payload.extend(&(encoded.len() as u32).to_be_bytes());
+        full_payload.extend(&crc16(payload).to_be_bytes());
+        if header_len >= ADAPTIVE_HEADER_LEN {
+            full_payload.push(parity as u8);
+        }
+        if let Some(tag) = tag {
+            full_payload.extend(&tag);
+        }
+        full_payload.extend(&encoded);
+
+        self.write_bytes(&full_payload);
         Ok(())
     }
 
+    /// Inject one frame of a multi-frame payload
+    /// The chunk is prefixed with INDEX (2) + TOTAL (2) and then injected like
+    /// a regular payload, so it shares the header CRC and RS protection.
+    pub fn inject_chunk(
+        &mut self,
+        payload: &[u8],
+        chunk_index: u16,
+        total_chunks: u16,
+    ) -> Result<(), &'static str> {
+        if chunk_index >= total_chunks {
+            return Err("Chunk index out of range");
+        }
+
+        let mut framed = chunk_index.to_be_bytes().to_vec();
+        framed.extend(&total_chunks.to_be_bytes());
+        framed.extend(payload);
+        self.inject_payload(&framed)
+    }
+
+    /// Extract a chunk written by `inject_chunk`
+    pub fn extract_chunk(&self) -> Result<FrameChunk, &'static str> {
+        let framed = self.extract_payload()?;
+        if framed.len() < CHUNK_HEADER_LEN {
+            return Err("Truncated chunk header");
+        }
+
+        let index = u16::from_be_bytes([framed[0], framed[1]]);
+        let total = u16::from_be_bytes([framed[2], framed[3]]);
+        if index >= total {
+            return Err("Chunk index out of range");
+        }
+
+        Ok(FrameChunk {
+            index,
+            total,
+            data: framed[CHUNK_HEADER_LEN..].to_vec(),
+        })
+    }
+
+    /// Chunk bytes that fit in one frame via `inject_chunk`
+    pub fn chunk_capacity_bytes(&self) -> usize {
+        self.capacity_bytes().saturating_sub(CHUNK_HEADER_LEN)
+    }
+
+    /// Write raw bytes into the carrier channel LSB along the prime walk
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        let bits = self.bytes_to_bits(bytes);
+        let mask = !((1u8 << self.config.bits_per_channel) - 1);
+        let offset = self.config.channel.offset();
+        let stride = self.format.stride();
+
+        for (pixel, &bit) in self.pixel_order(bits.len()).into_iter().zip(&bits) {
+            let i = pixel * stride + offset;
+
+            // Clear LSB and inject
+            self.pixel_data[i] = (self.pixel_data[i] & mask) | bit;
+        }
+    }
+
+    /// Bytes the carrier channel can hold, before any framing
+    fn carrier_bytes(&self) -> usize {
+        self.pixel_count() * self.config.bits_per_channel as usize / 8
+    }
+
+    /// Payload bytes that fit in the image, after the header and RS parity
+    /// (at the minimum parity level when adaptive)
+    pub fn capacity_bytes(&self) -> usize {
+        self.capacity_within(self.header_len())
+    }
+
+    /// Payload bytes that fit via `inject_payload_signed`
+    pub fn signed_capacity_bytes(&self) -> usize {
+        self.capacity_within(SIGNED_HEADER_LEN)
+    }
+
+    /// `capacity_bytes` behind a header of `header_len` bytes
+    fn capacity_within(&self, header_len: usize) -> usize {
+        let available = self.carrier_bytes().saturating_sub(header_len);
+
+        // Full 255-byte codewords plus whatever fits in a final short one
+        let block_data = RS_BLOCK - RS_PARITY;
+        let full_blocks = available / RS_BLOCK;
+        let remainder = available % RS_BLOCK;
+
+        full_blocks * block_data + remainder.saturating_sub(RS_PARITY)
+    }
+
     /// Convert bytes to bit chunks
     fn bytes_to_bits(&self, bytes: &[u8]) -> Vec<u8> {
         let mut bits = Vec::new();
-        let bits_per_byte = 8 / BITS_PER_CHANNEL as usize;
-        let mask = (1 << BITS_PER_CHANNEL) - 1;
+        let depth = self.config.bits_per_channel;
+        let bits_per_byte = 8 / depth as usize;
+        let mask = (1 << depth) - 1;
 
         for &byte in bytes {
-            for i in (0..bits_per_byte).rev() {
-                bits.push((byte >> (i as u8 * BITS_PER_CHANNEL)) & mask);
+            for i in 0..bits_per_byte {
+                bits.push((byte >> (depth * self.chunk_position(i, bits_per_byte))) & mask);
             }
         }
 
         bits
     }
 
+    /// Chunk index within the byte (0 = least significant) of the i-th
+    /// chunk in stream order
+    fn chunk_position(&self, i: usize, bits_per_byte: usize) -> u8 {
+        match self.bit_order {
+            BitOrder::MsbFirst => (bits_per_byte - 1 - i) as u8,
+            BitOrder::LsbFirst => i as u8,
+        }
+    }
+
     /// Get the modified pixel data
     pub fn get_pixel_data(&self) -> &[u8] {
         &self.pixel_data
@@ -280,6 +1403,236 @@ impl OpticalCarrier {
     pub fn dimensions(&self) -> (u32, u32) {
         (self.width, self.height)
     }
+
+    /// Compare the current pixels against the pre-injection frame
+    /// (`mean_abs_change[3]` stays zero for RGB buffers)
+    pub fn injection_delta(&self, original: &[u8]) -> Result<InjectionStats, &'static str> {
+        if original.len() != self.pixel_data.len() {
+            return Err("Frame size mismatch");
+        }
+
+        let mut modified_bytes = 0;
+        let mut channel_sums = [0.0f64; 4];
+        let mut squared_error = 0.0f64;
+
+        for (i, (&before, &after)) in original.iter().zip(&self.pixel_data).enumerate() {
+            let diff = (after as f64 - before as f64).abs();
+            if diff > 0.0 {
+                modified_bytes += 1;
+            }
+            channel_sums[i % self.format.stride()] += diff;
+            squared_error += diff * diff;
+        }
+
+        let pixels = self.pixel_count().max(1) as f64;
+        let mean_abs_change = channel_sums.map(|sum| sum / pixels);
+
+        let mse = squared_error / original.len().max(1) as f64;
+        let psnr = if mse == 0.0 {
+            f64::INFINITY
+        } else {
+            10.0 * (255.0 * 255.0 / mse).log10()
+        };
+
+        Ok(InjectionStats {
+            modified_bytes,
+            mean_abs_change,
+            psnr,
+        })
+    }
+}
+
+// ============================================================================
+// TILED EXTRACTION
+// ============================================================================
+
+/// Source of pixel rows for `OpticalCarrier::extract_payload_tiled`
+/// Closures `FnMut(first_row, rows, buf)` implement this directly.
+pub trait TileReader {
+    /// Fill `buf` (exactly `rows` rows, in the carrier's pixel format) with
+    /// rows `first_row..first_row + rows`
+    fn read_rows(
+        &mut self,
+        first_row: u32,
+        rows: u32,
+        buf: &mut [u8],
+    ) -> Result<(), &'static str>;
+}
+
+impl<F> TileReader for F
+where
+    F: FnMut(u32, u32, &mut [u8]) -> Result<(), &'static str>,
+{
+    fn read_rows(
+        &mut self,
+        first_row: u32,
+        rows: u32,
+        buf: &mut [u8],
+    ) -> Result<(), &'static str> {
+        self(first_row, rows, buf)
+    }
+}
+
+/// The single tile currently held during tiled extraction
+struct TileCursor<'a, R> {
+    reader: &'a mut R,
+    tile_rows: u32,
+    tile: Vec<u8>,
+    loaded: Option<usize>,
+}
+
+// ============================================================================
+// MULTI-FRAME ASSEMBLY
+// ============================================================================
+
+/// One frame's share of a multi-frame payload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameChunk {
+    pub index: u16,
+    pub total: u16,
+    pub data: Vec<u8>,
+}
+
+/// Collects chunks from a frame sequence, in any order, and rebuilds the
+/// original payload once every index is present
+#[derive(Debug, Clone, Default)]
+pub struct FrameAssembler {
+    total: Option<u16>,
+    chunks: HashMap<u16, Vec<u8>>,
+}
+
+impl FrameAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a chunk; rejects duplicates and chunks that disagree on the total
+    pub fn add(&mut self, chunk: FrameChunk) -> Result<(), &'static str> {
+        if chunk.index >= chunk.total {
+            return Err("Chunk index out of range");
+        }
+        if self.total.is_some_and(|total| total != chunk.total) {
+            return Err("Inconsistent chunk total");
+        }
+        if self.chunks.contains_key(&chunk.index) {
+            return Err("Duplicate chunk");
+        }
+
+        self.total = Some(chunk.total);
+        self.chunks.insert(chunk.index, chunk.data);
+        Ok(())
+    }
+
+    /// Extract the chunk carried by a frame and add it
+    pub fn add_frame<W: WalkSource>(
+        &mut self,
+        carrier: &OpticalCarrier<W>,
+    ) -> Result<(), &'static str> {
+        self.add(carrier.extract_chunk()?)
+    }
+
+    /// Indices not yet received, in ascending order
+    pub fn missing(&self) -> Vec<u16> {
+        match self.total {
+            Some(total) => (0..total).filter(|i| !self.chunks.contains_key(i)).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Whether every chunk of the sequence has been received
+    pub fn is_complete(&self) -> bool {
+        self.total.is_some() && self.missing().is_empty()
+    }
+
+    /// Concatenate the chunks in index order
+    pub fn assemble(&self) -> Result<Vec<u8>, &'static str> {
+        let total = self.total.ok_or("No chunks received")?;
+
+        let mut payload = Vec::new();
+        for index in 0..total {
+            payload.extend(self.chunks.get(&index).ok_or("Missing chunks")?);
+        }
+        Ok(payload)
+    }
+}
+
+// ============================================================================
+// STRUCTURED PAYLOADS (TLV)
+// ============================================================================
+
+/// Serializes tagged fields into a type-length-value stream:
+/// TAG (1) + LENGTH (4, big-endian) + VALUE per record, in insertion order.
+/// The result is an ordinary payload for `inject_payload`.
+#[derive(Debug, Clone, Default)]
+pub struct PayloadBuilder {
+    bytes: Vec<u8>,
+}
+
+impl PayloadBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a record
+    pub fn field(mut self, tag: u8, value: &[u8]) -> Self {
+        self.bytes.push(tag);
+        self.bytes.extend(&(value.len() as u32).to_be_bytes());
+        self.bytes.extend(value);
+        self
+    }
+
+    /// The TLV stream
+    pub fn build(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// Parses a TLV stream written by `PayloadBuilder`, one record at a time
+/// A record whose length runs past the buffer yields an error and ends
+/// the iteration.
+pub struct PayloadReader<'a> {
+    bytes: &'a [u8],
+    failed: bool,
+}
+
+impl<'a> PayloadReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        PayloadReader { bytes, failed: false }
+    }
+
+    /// Every record as (tag, value), or the first parse error
+    pub fn read_all(self) -> Result<Vec<(u8, Vec<u8>)>, &'static str> {
+        self.map(|record| record.map(|(tag, value)| (tag, value.to_vec())))
+            .collect()
+    }
+}
+
+impl<'a> Iterator for PayloadReader<'a> {
+    type Item = Result<(u8, &'a [u8]), &'static str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.bytes.is_empty() {
+            return None;
+        }
+        if self.bytes.len() < TLV_HEADER_LEN {
+            self.failed = true;
+            return Some(Err("Truncated TLV header"));
+        }
+
+        let tag = self.bytes[0];
+        let length =
+            u32::from_be_bytes([self.bytes[1], self.bytes[2], self.bytes[3], self.bytes[4]])
+                as usize;
+        let rest = &self.bytes[TLV_HEADER_LEN..];
+        if length > rest.len() {
+            self.failed = true;
+            return Some(Err("TLV length overruns payload"));
+        }
+
+        let (value, remaining) = rest.split_at(length);
+        self.bytes = remaining;
+        Some(Ok((tag, value)))
+    }
 }
 
 // ============================================================================
@@ -324,6 +1677,11 @@ mod wasm {
                 .map_err(|e| JsValue::from_str(e))
         }
 
+        #[wasm_bindgen]
+        pub fn capacity_bytes(&self) -> usize {
+            self.inner.capacity_bytes()
+        }
+
         #[wasm_bindgen]
         pub fn get_pixel_data(&self) -> Vec<u8> {
             self.inner.get_pixel_data().to_vec()
@@ -335,39 +1693,6 @@ mod wasm {
 // DEMO / TEST
 // ============================================================================
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_encode_decode() {
-        let mut carrier = OpticalCarrier::new(100, 100);
-
-        // Create dummy image (100x100 RGBA)
-        carrier.pixel_data = vec![128; 100 * 100 * 4];
-
-        // Inject payload
-        let payload = b"Hello, TENT v4.0!";
-        carrier.inject_payload(payload).unwrap();
-
-        // Extract payload
-        let extracted = carrier.extract_payload().unwrap();
-        assert_eq!(extracted, payload);
-    }
-
-    #[test]
-    fn test_prime_walk() {
-        let mut walk = PrimeWalk::new(12345);
-        let positions: Vec<usize> = (0..10).map(|_| walk.next(1000)).collect();
-
-        // Reset and verify reproducibility
-        walk.reset(12345);
-        let positions2: Vec<usize> = (0..10).map(|_| walk.next(1000)).collect();
-
-        assert_eq!(positions, positions2);
-    }
-}
-
 fn main() {
     println!("╔══════════════════════════════════════════════════════════════╗");
     println!("║  TENT v4.0 VISUAL CODEC - The Optical Carrier                ║");
@@ -405,3 +1730,573 @@ fn main() {
     println!("\n  Protocol: CRYSTAL_REFINER");
     println!("  Status: LIGHT → CODE → REALITY");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode() {
+        let mut carrier = OpticalCarrier::new(100, 100);
+
+        // Create dummy image (100x100 RGBA)
+        carrier.pixel_data = vec![128; 100 * 100 * 4];
+
+        // Inject payload
+        let payload = b"Hello, TENT v4.0!";
+        carrier.inject_payload(payload).unwrap();
+
+        // Extract payload
+        let extracted = carrier.extract_payload().unwrap();
+        assert_eq!(extracted, payload);
+    }
+
+    #[test]
+    fn test_channel_and_depth_round_trip() {
+        let payload = b"Channel test";
+
+        for &channel in &[Channel::Red, Channel::Green, Channel::Blue, Channel::Alpha] {
+            for &bits_per_channel in &[1u8, 2, 4] {
+                let config = CarrierConfig { channel, bits_per_channel };
+                let mut carrier = OpticalCarrier::with_config(64, 64, config).unwrap();
+                carrier.pixel_data = vec![128; 64 * 64 * 4];
+
+                carrier.inject_payload(payload).unwrap();
+                assert_eq!(carrier.extract_payload().unwrap(), payload);
+            }
+        }
+
+        let bad = CarrierConfig { channel: Channel::Red, bits_per_channel: 3 };
+        assert!(OpticalCarrier::with_config(64, 64, bad).is_err());
+    }
+
+    #[test]
+    fn test_rgb_and_rgba_buffers() {
+        let rgb = noise(48 * 48 * 3, 6);
+        let rgba: Vec<u8> = rgb.chunks(3).flat_map(|p| [p[0], p[1], p[2], 255]).collect();
+        let payload = b"same picture, different stride";
+        let config = CarrierConfig::default();
+
+        let mut rgb_carrier =
+            OpticalCarrier::with_format(48, 48, PixelFormat::Rgb, config).unwrap();
+        rgb_carrier.ingest_frame(&rgb);
+        rgb_carrier.inject_payload(payload).unwrap();
+        assert_eq!(rgb_carrier.extract_payload().unwrap(), payload);
+
+        let mut rgba_carrier = OpticalCarrier::new(48, 48);
+        rgba_carrier.ingest_frame(&rgba);
+        rgba_carrier.inject_payload(payload).unwrap();
+        assert_eq!(rgba_carrier.extract_payload().unwrap(), payload);
+
+        // Both layouts hide the payload in the same pixels
+        let stripped: Vec<u8> = rgba_carrier
+            .get_pixel_data()
+            .chunks(4)
+            .flat_map(|p| [p[0], p[1], p[2]])
+            .collect();
+        assert_eq!(stripped, rgb_carrier.get_pixel_data());
+
+        rgb_carrier.ingest_frame(&rgb[..rgb.len() - 1]);
+        assert!(rgb_carrier.inject_payload(payload).is_err());
+        assert!(rgb_carrier.extract_payload().is_err());
+
+        let alpha = CarrierConfig { channel: Channel::Alpha, bits_per_channel: 2 };
+        assert!(OpticalCarrier::with_format(48, 48, PixelFormat::Rgb, alpha).is_err());
+    }
+
+    #[test]
+    fn test_payload_is_scattered() {
+        let mut carrier = OpticalCarrier::new(100, 100);
+        carrier.pixel_data = vec![128; 100 * 100 * 4];
+        let original = carrier.pixel_data.clone();
+
+        let payload = b"Spread the light";
+        carrier.inject_payload(payload).unwrap();
+
+        // Bits needed: (8-byte header + payload + parity) * 4 two-bit chunks
+        let bits_needed = (8 + payload.len() + RS_PARITY) * 4;
+        let changed: Vec<usize> = (0..100 * 100)
+            .filter(|&p| carrier.pixel_data[p * 4 + 2] != original[p * 4 + 2])
+            .collect();
+
+        assert!(changed.iter().any(|&p| p >= bits_needed), "bits not scattered");
+        assert_eq!(carrier.extract_payload().unwrap(), payload);
+    }
+
+    #[test]
+    fn test_capacity() {
+        // 40x40 pixels at 2 bits = 400 bytes: 11 header, 255 + 134 of codewords
+        let mut carrier = OpticalCarrier::new(40, 40);
+        carrier.pixel_data = vec![128; 40 * 40 * 4];
+        assert_eq!(carrier.capacity_bytes(), 239 + 118);
+
+        let exact = vec![0x5Au8; carrier.capacity_bytes()];
+        carrier.inject_payload(&exact).unwrap();
+        assert_eq!(carrier.extract_payload().unwrap(), exact);
+    }
+
+    #[test]
+    fn test_capacity_overflow_leaves_pixels_untouched() {
+        let mut carrier = OpticalCarrier::new(40, 40);
+        carrier.pixel_data = vec![128; 40 * 40 * 4];
+        let original = carrier.pixel_data.clone();
+
+        let too_big = vec![0x5Au8; carrier.capacity_bytes() + 1];
+        assert!(carrier.inject_payload(&too_big).is_err());
+        assert_eq!(carrier.pixel_data, original);
+    }
+
+    #[test]
+    fn test_injection_delta() {
+        let original = noise(64 * 64 * 4, 4);
+        let payload = b"detectability budget";
+
+        let psnr_at = |bits_per_channel| {
+            let config = CarrierConfig {
+                channel: Channel::Blue,
+                bits_per_channel,
+            };
+            let mut carrier = OpticalCarrier::with_config(64, 64, config).unwrap();
+            carrier.ingest_frame(&original);
+            carrier.inject_payload(payload).unwrap();
+
+            let stats = carrier.injection_delta(&original).unwrap();
+            assert!(stats.modified_bytes > 0);
+            assert_eq!(stats.mean_abs_change[0], 0.0);
+            assert!(stats.mean_abs_change[2] > 0.0);
+            stats.psnr
+        };
+
+        assert!(psnr_at(1) > psnr_at(4));
+
+        let carrier = OpticalCarrier::new(64, 64);
+        assert!(carrier.injection_delta(&original[..16]).is_err());
+    }
+
+    #[test]
+    fn test_multi_frame_reassembly() {
+        let payload = noise(10 * 1024, 5);
+
+        let mut frames: Vec<OpticalCarrier> = (0..3)
+            .map(|i| {
+                let mut carrier = OpticalCarrier::new(128, 128);
+                carrier.ingest_frame(&noise(128 * 128 * 4, 10 + i));
+                carrier
+            })
+            .collect();
+        assert!(frames[0].capacity_bytes() < payload.len());
+
+        let chunk_len = payload.len().div_ceil(frames.len());
+        assert!(chunk_len <= frames[0].chunk_capacity_bytes());
+        for (i, (frame, chunk)) in frames.iter_mut().zip(payload.chunks(chunk_len)).enumerate() {
+            frame.inject_chunk(chunk, i as u16, 3).unwrap();
+        }
+
+        // Frames arrive out of order
+        let mut assembler = FrameAssembler::new();
+        for &i in &[2, 0] {
+            assembler.add_frame(&frames[i]).unwrap();
+        }
+        assert!(!assembler.is_complete());
+        assert_eq!(assembler.missing(), vec![1]);
+        assert_eq!(assembler.assemble(), Err("Missing chunks"));
+
+        assert_eq!(assembler.add_frame(&frames[0]), Err("Duplicate chunk"));
+        assembler.add_frame(&frames[1]).unwrap();
+        assert!(assembler.is_complete());
+        assert_eq!(assembler.assemble().unwrap(), payload);
+    }
+
+    #[test]
+    fn test_frame_assembler_rejects_inconsistent_total() {
+        let chunk = |index, total| FrameChunk { index, total, data: vec![index as u8] };
+
+        let mut assembler = FrameAssembler::new();
+        assert_eq!(assembler.assemble(), Err("No chunks received"));
+        assembler.add(chunk(0, 2)).unwrap();
+        assert_eq!(assembler.add(chunk(1, 3)), Err("Inconsistent chunk total"));
+        assert_eq!(assembler.add(chunk(2, 2)), Err("Chunk index out of range"));
+
+        let mut carrier = OpticalCarrier::new(16, 16);
+        assert!(carrier.inject_chunk(b"x", 2, 2).is_err());
+    }
+
+    #[test]
+    fn test_tlv_round_trip() {
+        let body = noise(300, 7);
+        let payload = PayloadBuilder::new()
+            .field(1, b"Title")
+            .field(2, &body)
+            .field(3, &[])
+            .build();
+
+        let mut carrier = OpticalCarrier::new(64, 64);
+        carrier.ingest_frame(&noise(64 * 64 * 4, 8));
+        carrier.inject_payload(&payload).unwrap();
+
+        let fields = PayloadReader::new(&carrier.extract_payload().unwrap()).read_all().unwrap();
+        assert_eq!(fields, vec![(1, b"Title".to_vec()), (2, body), (3, Vec::new())]);
+    }
+
+    #[test]
+    fn test_tlv_malformed() {
+        let payload = PayloadBuilder::new().field(9, b"overrun").build();
+
+        let short = &payload[..payload.len() - 1];
+        assert_eq!(PayloadReader::new(short).read_all(), Err("TLV length overruns payload"));
+        assert_eq!(PayloadReader::new(&payload[..3]).read_all(), Err("Truncated TLV header"));
+        assert_eq!(PayloadReader::new(&[]).read_all(), Ok(Vec::new()));
+
+        let mut reader = PayloadReader::new(short);
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.next().is_none());
+    }
+
+    /// Deterministic noise for filling carriers
+    fn noise(len: usize, seed: u64) -> Vec<u8> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                (state >> 56) as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_header_crc_round_trip() {
+        let mut carrier = OpticalCarrier::new(64, 64);
+        carrier.pixel_data = noise(64 * 64 * 4, 1);
+
+        carrier.inject_payload(b"checksummed").unwrap();
+        assert_eq!(carrier.extract_payload().unwrap(), b"checksummed");
+        assert_eq!(crc16(b"123456789"), 0x29B1);
+    }
+
+    #[test]
+    fn test_adaptive_parity() {
+        let image = noise(128 * 128 * 4, 6);
+        let payload = b"small payload, large image";
+
+        let mut adaptive = OpticalCarrier::new(128, 128).with_max_parity(64);
+        adaptive.ingest_frame(&image);
+        assert_eq!(adaptive.max_parity(), 64);
+        assert_eq!(adaptive.parity_for(payload.len()), 64);
+        adaptive.inject_payload(payload).unwrap();
+
+        let mut fixed = OpticalCarrier::new(128, 128);
+        fixed.ingest_frame(&image);
+        assert_eq!(fixed.parity_for(payload.len()), RS_PARITY);
+        fixed.inject_payload(payload).unwrap();
+
+        // Damage 20 bytes after the header: beyond 16 parity bytes, within 64
+        let corrupt = |carrier: &mut OpticalCarrier, header_len: usize| {
+            let chunks = carrier.pixel_order((header_len + 20) * 4);
+            for byte in header_len..header_len + 20 {
+                carrier.pixel_data[chunks[byte * 4] * 4 + 2] ^= 0b11;
+            }
+        };
+        corrupt(&mut adaptive, ADAPTIVE_HEADER_LEN);
+        corrupt(&mut fixed, HEADER_LEN);
+        assert!(fixed.extract_payload().is_err());
+
+        // The level travels in the header, so a default reader decodes it
+        let mut reader = OpticalCarrier::new(128, 128);
+        reader.ingest_frame(adaptive.get_pixel_data());
+        assert_eq!(reader.extract_payload().unwrap(), payload);
+
+        // A full image falls back to the minimum level
+        let tight = OpticalCarrier::new(40, 40).with_max_parity(1000);
+        assert_eq!(tight.max_parity(), MAX_RS_PARITY);
+        assert_eq!(tight.parity_for(tight.capacity_bytes()), RS_PARITY);
+    }
+
+    #[test]
+    fn test_hmac_sha256_vectors() {
+        let hex = |bytes: [u8; 32]| bytes.iter().map(|b| format!("{:02x}", b)).collect::<String>();
+
+        // FIPS 180-4 examples, one and two blocks
+        assert_eq!(
+            hex(sha256(b"abc")),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            hex(sha256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+
+        // RFC 4231 cases 2 and 6 (key longer than a block)
+        assert_eq!(
+            hex(hmac_sha256(b"Jefe", b"what do ya want for nothing?")),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        let long_key_message = b"Test Using Larger Than Block-Size Key - Hash Key First";
+        assert_eq!(
+            hex(hmac_sha256(&[0xAA; 131], long_key_message)),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+    }
+
+    #[test]
+    fn test_signed_payload() {
+        let mut carrier = OpticalCarrier::new(64, 64);
+        carrier.pixel_data = noise(64 * 64 * 4, 7);
+        let payload = b"signed orders";
+
+        carrier.inject_payload_signed(payload, b"secret").unwrap();
+        assert_eq!(carrier.extract_payload_verified(b"secret").unwrap(), payload);
+        assert_eq!(carrier.extract_payload_verified(b"guess"), Err("bad signature"));
+        assert_eq!(carrier.extract_payload().unwrap(), payload);
+        assert_eq!(carrier.signed_capacity_bytes(), carrier.capacity_bytes() - TAG_LEN - 1);
+
+        // A flipped byte in the pixels is repaired before the tag is checked
+        let chunks = carrier.pixel_order((SIGNED_HEADER_LEN + 1) * 4);
+        carrier.pixel_data[chunks[SIGNED_HEADER_LEN * 4] * 4 + 2] ^= 0b11;
+        assert_eq!(carrier.extract_payload_verified(b"secret").unwrap(), payload);
+
+        // A payload byte flipped and re-encoded under the old tag passes RS
+        // and CRC, but not the signature
+        let raw = carrier.bits_to_bytes(&carrier.extract_lsb());
+        let mut forged = payload.to_vec();
+        forged[0] ^= 0x01;
+        let encoded = carrier.rs.encode(&forged);
+        let mut frame = raw[..ADAPTIVE_HEADER_LEN].to_vec();
+        frame[5..9].copy_from_slice(&(encoded.len() as u32).to_be_bytes());
+        frame[9..11].copy_from_slice(&crc16(&forged).to_be_bytes());
+        frame.extend(&raw[ADAPTIVE_HEADER_LEN..SIGNED_HEADER_LEN]);
+        frame.extend(&encoded);
+        carrier.write_bytes(&frame);
+        assert_eq!(carrier.extract_payload().unwrap(), forged);
+        assert_eq!(carrier.extract_payload_verified(b"secret"), Err("bad signature"));
+
+        // Unsigned payloads never verify
+        carrier.inject_payload(payload).unwrap();
+        assert_eq!(carrier.extract_payload_verified(b"secret"), Err("Payload is not signed"));
+    }
+
+    #[test]
+    fn test_tiled_extraction_matches_whole_buffer() {
+        let (width, height) = (48u32, 37u32);
+        let mut carrier = OpticalCarrier::new(width, height);
+        carrier.pixel_data = noise((width * height * 4) as usize, 4);
+        let payload: Vec<u8> = (0..200u32).map(|i| (i * 13 + 5) as u8).collect();
+        carrier.inject_payload(&payload).unwrap();
+        let image = carrier.get_pixel_data().to_vec();
+
+        // 5 does not divide 37, so the last tile is short
+        let row_bytes = width as usize * 4;
+        let mut largest_tile = 0;
+        let mut reader = |first_row: u32, rows: u32, buf: &mut [u8]| {
+            let start = first_row as usize * row_bytes;
+            buf.copy_from_slice(&image[start..start + rows as usize * row_bytes]);
+            largest_tile = largest_tile.max(buf.len());
+            Ok(())
+        };
+
+        let streaming =
+            OpticalCarrier::streaming(width, height, PixelFormat::Rgba, CarrierConfig::default())
+                .unwrap();
+        assert_eq!(
+            streaming.extract_payload_tiled(&mut reader, 0),
+            Err("Tile must span at least one row")
+        );
+        let tiled = streaming.extract_payload_tiled(&mut reader, 5).unwrap();
+        assert_eq!(tiled, carrier.extract_payload().unwrap());
+        assert_eq!(tiled, payload);
+        assert!(largest_tile <= 5 * row_bytes);
+
+        let mut blank = |_: u32, _: u32, buf: &mut [u8]| {
+            buf.fill(0);
+            Ok(())
+        };
+        assert_eq!(
+            streaming.extract_payload_tiled(&mut blank, 8),
+            Err("No TENT payload found")
+        );
+    }
+
+    #[test]
+    fn test_bit_order() {
+        let image = noise(64 * 64 * 4, 5);
+        let carrier_with = |order| {
+            let mut carrier = OpticalCarrier::new(64, 64).with_bit_order(order);
+            carrier.ingest_frame(&image);
+            carrier
+        };
+        assert_eq!(OpticalCarrier::new(8, 8).bit_order(), BitOrder::MsbFirst);
+
+        for order in [BitOrder::MsbFirst, BitOrder::LsbFirst] {
+            let mut carrier = carrier_with(order);
+            carrier.inject_payload(b"interop").unwrap();
+            assert_eq!(carrier.extract_payload().unwrap(), b"interop");
+        }
+
+        let mut lsb = carrier_with(BitOrder::LsbFirst);
+        lsb.inject_payload(b"interop").unwrap();
+        let mut msb = carrier_with(BitOrder::MsbFirst);
+        msb.ingest_frame(lsb.get_pixel_data());
+        assert!(msb.extract_payload().is_err());
+        assert_eq!(msb.bytes_to_bits(&[0b1110_0100]), [3, 2, 1, 0]);
+        assert_eq!(lsb.bytes_to_bits(&[0b1110_0100]), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_detect_payload() {
+        let mut carrier = OpticalCarrier::new(64, 64);
+        carrier.pixel_data = vec![128; 64 * 64 * 4];
+        let clean = carrier.detect_payload();
+        assert!(!clean.is_present());
+        assert_eq!(clean.declared_length, None);
+
+        carrier.inject_payload(b"already here").unwrap();
+        let probe = carrier.detect_payload();
+        assert!(probe.is_present());
+        assert_eq!(probe.bit_offset, Some(0));
+        assert_eq!(probe.declared_length, Some(carrier.rs.encoded_len(12)));
+    }
+
+    #[test]
+    fn test_header_crc_mismatch() {
+        let mut carrier = OpticalCarrier::new(64, 64);
+        carrier.pixel_data = vec![128; 64 * 64 * 4];
+
+        // Valid RS codeword, wrong checksum
+        let encoded = carrier.rs.encode(b"tampered");
+        let mut frame = TENT_MAGIC.to_vec();
+        frame.push(FORMAT_VERSION);
+        frame.extend(&(encoded.len() as u32).to_be_bytes());
+        frame.extend(&(crc16(b"tampered") ^ 0xFFFF).to_be_bytes());
+        frame.extend(&encoded);
+        carrier.write_bytes(&frame);
+
+        assert_eq!(carrier.extract_payload(), Err("CRC mismatch"));
+    }
+
+    #[test]
+    fn test_legacy_header() {
+        let mut carrier = OpticalCarrier::new(64, 64);
+        carrier.pixel_data = noise(64 * 64 * 4, 5);
+
+        // Built exactly as the version 0 `inject_payload` did: XOR parity,
+        // then 2-bit chunks MSB first into each pixel's Blue LSBs in order
+        let payload = b"version zero";
+        let mut encoded = payload.to_vec();
+        for i in 0..LEGACY_PARITY {
+            let mut parity: u8 = 0;
+            for (j, &byte) in payload.iter().enumerate() {
+                parity ^= byte.rotate_left((i as u32 + j as u32) % 8);
+            }
+            encoded.push(parity);
+        }
+        let mut frame = TENT_MAGIC.to_vec();
+        frame.extend(&(encoded.len() as u32).to_be_bytes());
+        frame.extend(&encoded);
+
+        let bits = frame.iter().flat_map(|&byte| (0..4).rev().map(move |i| (byte >> (i * 2)) & 3));
+        for (blue, bit) in carrier.pixel_data.iter_mut().skip(2).step_by(4).zip(bits) {
+            *blue = (*blue & !3) | bit;
+        }
+
+        assert_eq!(carrier.extract_payload().unwrap(), payload);
+    }
+
+    #[test]
+    fn test_false_magic_in_noise() {
+        let mut carrier = OpticalCarrier::new(64, 64);
+        carrier.pixel_data = noise(64 * 64 * 4, 2);
+
+        // A coincidental "TENT" followed by noise must not decode
+        let mut frame = TENT_MAGIC.to_vec();
+        frame.push(FORMAT_VERSION);
+        frame.extend(&64u32.to_be_bytes());
+        frame.extend(noise(66, 3));
+        carrier.write_bytes(&frame);
+
+        assert!(carrier.extract_payload().is_err());
+    }
+
+    #[test]
+    fn test_reed_solomon_corrects_errors() {
+        let rs = ReedSolomon::new(RS_PARITY);
+        let payload: Vec<u8> = (0..600u32).map(|i| (i * 7 + 3) as u8).collect();
+        let mut encoded = rs.encode(&payload);
+        assert_eq!(encoded.len(), rs.encoded_len(payload.len()));
+
+        // Up to 8 byte errors per 255-byte block are corrected
+        for &pos in &[0usize, 17, 42, 99, 150, 200, 230, 254, 300, 510] {
+            encoded[pos] ^= 0xA5;
+        }
+        assert_eq!(rs.decode(&encoded).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_reed_solomon_too_many_errors() {
+        let rs = ReedSolomon::new(RS_PARITY);
+        let payload = b"Hello, TENT v4.0!";
+        let mut encoded = rs.encode(payload);
+
+        for byte in encoded.iter_mut().take(12) {
+            *byte ^= 0xFF;
+        }
+        assert!(rs.decode(&encoded).is_err());
+    }
+
+    /// Deterministic stand-in for an external walk source
+    #[derive(Clone)]
+    struct StrideWalk {
+        position: usize,
+    }
+
+    impl WalkSource for StrideWalk {
+        fn next(&mut self, max: usize) -> usize {
+            self.position = (self.position + 7919) % max;
+            self.position
+        }
+
+        fn reset(&mut self, seed: u64) {
+            self.position = seed as usize;
+        }
+    }
+
+    #[test]
+    fn test_custom_walk_source() {
+        let frame = noise(64 * 64 * 4, 9);
+        let payload = b"walked a different path";
+
+        let mut custom = OpticalCarrier::new(64, 64).with_walk_source(StrideWalk { position: 0 });
+        custom.ingest_frame(&frame);
+        custom.inject_payload(payload).unwrap();
+        assert_eq!(custom.extract_payload().unwrap(), payload);
+
+        // The default walk reads other pixels
+        let mut default = OpticalCarrier::new(64, 64);
+        default.ingest_frame(custom.get_pixel_data());
+        assert!(default.extract_payload().is_err());
+    }
+
+    #[test]
+    fn test_prime_walk_covers_space() {
+        let mut walk = PrimeWalk::with_primes(7, vec![3, 5, 7, 11]).unwrap();
+        let mut seen = [false; 64];
+
+        for _ in 0..64 {
+            let pos = walk.next(64);
+            assert!(!seen[pos], "position {} repeated before full coverage", pos);
+            seen[pos] = true;
+        }
+
+        assert!(PrimeWalk::with_primes(7, Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_prime_walk() {
+        let mut walk = PrimeWalk::new(12345);
+        let positions: Vec<usize> = (0..10).map(|_| walk.next(1000)).collect();
+
+        // Reset and verify reproducibility
+        walk.reset(12345);
+        let positions2: Vec<usize> = (0..10).map(|_| walk.next(1000)).collect();
+
+        assert_eq!(positions, positions2);
+    }
+}
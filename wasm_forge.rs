@@ -6,12 +6,21 @@
 //! When compiled with wasm-pack, it produces a .wasm file that runs in browsers.
 //!
 //! Build command: wasm-pack build --target web
+//! Depends on the geometry_core crate for the unified validator and the
+//! shared word hash and anchor words.
 //!
 //! "The user sees the beauty. The machine reads the truth."
 
+use std::borrow::Cow;
+use std::collections::HashSet;
 use std::f64::consts::PI;
+use std::sync::OnceLock;
 use wasm_bindgen::prelude::*;
 
+// geometry_core is a regular dependency with its default `std` feature, so
+// the platform float routines are used
+use geometry_core::{word_hash, UnifiedFieldValidator, ANCHOR_WORDS};
+
 // =============================================================================
 // CONSTANTS
 // =============================================================================
@@ -19,6 +28,11 @@ use wasm_bindgen::prelude::*;
 const PHI: f64 = 1.618033988749895; // Golden Ratio
 const DELTA: f64 = 2.414213562373095; // Silver Ratio
 
+const GRAVITY: f32 = 0.001; // Default mass-to-center gravity coefficient
+const REPULSION: f32 = 0.0001; // Default pairwise repulsion strength
+const MAX_DISPLACEMENT: f32 = 0.01; // Per-step repulsion cap
+const MIN_DISTANCE: f32 = 0.01; // Softening for near-coincident pigments
+
 // Buzzwords (antimatter - negative density)
 const BUZZWORDS: &[&str] = &[
     "synergy",
@@ -38,25 +52,115 @@ const BUZZWORDS: &[&str] = &[
     "deliverable",
 ];
 
-// Technical anchors (heavy matter - positive density)
-const ANCHORS: &[&str] = &[
-    "riemann",
-    "zeta",
-    "prime",
-    "theorem",
-    "proof",
-    "derivative",
-    "integral",
-    "tensor",
-    "quantum",
-    "entropy",
-    "thermodynamic",
-    "algorithm",
-    "function",
-    "equation",
-    "hypothesis",
-    "axiom",
-];
+// =============================================================================
+// LEXICON
+// =============================================================================
+
+/// Word sets and the mass assigned to each class of word
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct Lexicon {
+    buzzwords: HashSet<String>,
+    anchors: HashSet<String>,
+    buzzword_stems: HashSet<String>,
+    anchor_stems: HashSet<String>,
+    pub stemming: bool,     // Fall back to stem matching ("theorems" -> "theorem")
+    pub buzzword_mass: f32, // Antimatter
+    pub anchor_mass: f32,   // Heavy matter
+    pub light_mass: f32,    // Words of 3 bytes or fewer
+    pub normal_mass: f32,   // Everything else
+}
+
+#[wasm_bindgen]
+impl Lexicon {
+    /// The built-in lexicon, as `Default`
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Lexicon {
+        Self::default()
+    }
+
+    /// An empty lexicon with the default mass table
+    pub fn empty() -> Lexicon {
+        Lexicon {
+            buzzwords: HashSet::new(),
+            anchors: HashSet::new(),
+            buzzword_stems: HashSet::new(),
+            anchor_stems: HashSet::new(),
+            stemming: true,
+            buzzword_mass: -2.0,
+            anchor_mass: 5.0,
+            light_mass: 0.5,
+            normal_mass: 1.0,
+        }
+    }
+
+    pub fn add_buzzword(&mut self, word: &str) {
+        let key = normalize_word(word);
+        self.buzzword_stems.insert(stem(&key));
+        self.buzzwords.insert(key);
+    }
+
+    pub fn add_anchor(&mut self, word: &str) {
+        let key = normalize_word(word);
+        self.anchor_stems.insert(stem(&key));
+        self.anchors.insert(key);
+    }
+
+    /// Mass of a word, matched whole-word after normalization
+    /// Exact matches are checked first; with `stemming`, a miss is retried
+    /// on the word's stem against the stemmed entries
+    pub fn mass(&self, word: &str) -> f32 {
+        let key = normalize_word(word);
+
+        if self.buzzwords.contains(&key) {
+            return self.buzzword_mass;
+        } else if self.anchors.contains(&key) {
+            return self.anchor_mass;
+        } else if !self.stemming {
+            return self.unlisted_mass(word);
+        }
+
+        let root = stem(&key);
+        if self.buzzword_stems.contains(&root) {
+            self.buzzword_mass
+        } else if self.anchor_stems.contains(&root) {
+            self.anchor_mass
+        } else {
+            self.unlisted_mass(word)
+        }
+    }
+}
+
+impl Lexicon {
+    /// Mass of a word in neither list
+    fn unlisted_mass(&self, word: &str) -> f32 {
+        if word.len() <= 3 {
+            self.light_mass
+        } else {
+            self.normal_mass
+        }
+    }
+}
+
+impl Default for Lexicon {
+    /// The built-in BUZZWORDS and shared ANCHOR_WORDS lists
+    fn default() -> Self {
+        let mut lexicon = Lexicon::empty();
+        for word in BUZZWORDS {
+            lexicon.add_buzzword(word);
+        }
+        for word in ANCHOR_WORDS {
+            lexicon.add_anchor(word);
+        }
+        lexicon
+    }
+}
+
+/// Shared `Lexicon::default()`, built on first use
+fn default_lexicon() -> &'static Lexicon {
+    static LEXICON: OnceLock<Lexicon> = OnceLock::new();
+    LEXICON.get_or_init(Lexicon::default)
+}
 
 // =============================================================================
 // PIGMENT STRUCTURE
@@ -78,23 +182,31 @@ pub struct Pigment {
 
 #[wasm_bindgen]
 impl Pigment {
-    /// Create a pigment from a word
+    /// Create a pigment from a word using the default lexicon
     pub fn from_word(word: &str, index: u32, total: u32) -> Pigment {
-        let word_lower = word.to_lowercase();
+        Self::from_word_with(word, index, total, default_lexicon())
+    }
 
+    /// Create a pigment from a word using a custom lexicon
+    pub fn from_word_with(word: &str, index: u32, total: u32, lexicon: &Lexicon) -> Pigment {
+        Self::from_word_seeded(word, index, total, 0, lexicon)
+    }
+
+    /// Create a pigment whose initial position also depends on a layout seed
+    /// The position is a pure function of (word, index, total, seed); seed 0
+    /// gives the unseeded layout. Mass, resonance and color ignore the seed.
+    pub fn from_word_seeded(
+        word: &str,
+        index: u32,
+        total: u32,
+        seed: u64,
+        lexicon: &Lexicon,
+    ) -> Pigment {
         // Calculate mass based on word type
-        let mass = if BUZZWORDS.iter().any(|&b| word_lower.contains(b)) {
-            -2.0 // Antimatter!
-        } else if ANCHORS.iter().any(|&a| word_lower.contains(a)) {
-            5.0 // Heavy matter
-        } else if word.len() <= 3 {
-            0.5 // Light particles
-        } else {
-            1.0 // Normal matter
-        };
+        let mass = lexicon.mass(word);
 
-        // Calculate resonance from word hash
-        let hash = simple_hash(word);
+        // Calculate resonance from the shared word hash
+        let hash = word_hash(word, 0);
         let phase = (hash as f64 * PHI) % (2.0 * PI);
         let resonance = ((phase.sin() + 1.0) / 2.0) as f32;
 
@@ -102,8 +214,10 @@ impl Pigment {
         let (r, g, b) = mass_to_color(mass, resonance);
 
         // Initial position (will be adjusted by physics)
-        let angle = (index as f64 / total as f64) * 2.0 * PI;
-        let radius = 0.3 + (hash as f64 % 100.0) / 500.0;
+        let seed_mix = seed.wrapping_mul(0x9E3779B97F4A7C15);
+        let rotation = (seed_mix >> 11) as f64 / (1u64 << 53) as f64 * 2.0 * PI;
+        let angle = (index as f64 / total as f64) * 2.0 * PI + rotation;
+        let radius = 0.3 + ((hash ^ seed_mix) as f64 % 100.0) / 500.0;
         let x = 0.5 + (radius * angle.cos()) as f32;
         let y = 0.5 + (radius * angle.sin()) as f32;
 
@@ -123,6 +237,11 @@ impl Pigment {
     /// Positive mass -> moves toward center
     /// Negative mass -> moves toward edges
     pub fn apply_gravity(&mut self, center_x: f32, center_y: f32) {
+        self.apply_gravity_with(center_x, center_y, GRAVITY);
+    }
+
+    /// `apply_gravity` with a custom gravity coefficient
+    pub fn apply_gravity_with(&mut self, center_x: f32, center_y: f32, strength: f32) {
         let dx = center_x - self.x;
         let dy = center_y - self.y;
         let dist = (dx * dx + dy * dy).sqrt().max(0.01);
@@ -130,7 +249,7 @@ impl Pigment {
         // Gravity proportional to mass
         // Positive mass = attraction (toward center)
         // Negative mass = repulsion (toward edges)
-        let force = self.mass * 0.001 / dist;
+        let force = self.mass * strength / dist;
 
         self.x += dx * force;
         self.y += dy * force;
@@ -149,6 +268,12 @@ impl Pigment {
 #[wasm_bindgen]
 pub struct TruthCanvas {
     pigments: Vec<Pigment>,
+    /// The shared default until `set_lexicon` replaces it
+    lexicon: Cow<'static, Lexicon>,
+    repulsion: f32,
+    gravity_strength: f32,
+    energy: f32,
+    seed: u64,
     width: u32,
     height: u32,
 }
@@ -158,8 +283,19 @@ impl TruthCanvas {
     /// Create a new empty canvas
     #[wasm_bindgen(constructor)]
     pub fn new(width: u32, height: u32) -> TruthCanvas {
+        Self::new_seeded(width, height, 0)
+    }
+
+    /// Create a canvas with a layout seed
+    /// Identical seeds and text yield identical layouts, step for step
+    pub fn new_seeded(width: u32, height: u32, seed: u64) -> TruthCanvas {
         TruthCanvas {
             pigments: Vec::new(),
+            lexicon: Cow::Borrowed(default_lexicon()),
+            repulsion: REPULSION,
+            gravity_strength: GRAVITY,
+            energy: 0.0,
+            seed,
             width,
             height,
         }
@@ -168,24 +304,92 @@ impl TruthCanvas {
     /// Ingest a narrative and convert to pigments
     pub fn ingest_narrative(&mut self, text: &str) {
         self.pigments.clear();
+        self.energy = 0.0;
 
         // Split into words
         let words: Vec<&str> = text.split_whitespace().collect();
         let total = words.len() as u32;
 
         for (i, word) in words.iter().enumerate() {
-            let pigment = Pigment::from_word(word, i as u32, total);
+            let pigment =
+                Pigment::from_word_seeded(word, i as u32, total, self.seed, &self.lexicon);
             self.pigments.push(pigment);
         }
     }
 
+    /// Replace the lexicon used by later calls to ingest_narrative
+    pub fn set_lexicon(&mut self, lexicon: Lexicon) {
+        self.lexicon = Cow::Owned(lexicon);
+    }
+
+    /// Set the pairwise repulsion strength (0 disables it)
+    pub fn set_repulsion(&mut self, strength: f32) {
+        self.repulsion = strength;
+    }
+
+    /// Set the gravity coefficient pulling pigments by mass (0 disables it)
+    pub fn set_gravity_strength(&mut self, strength: f32) {
+        self.gravity_strength = strength;
+    }
+
+    pub fn gravity_strength(&self) -> f32 {
+        self.gravity_strength
+    }
+
+    /// Kinetic-like energy of the last step: Σ ½|Δp|² over all pigments
+    /// Falls toward 0 as the layout settles; 0 before the first step.
+    pub fn total_energy(&self) -> f32 {
+        self.energy
+    }
+
     /// Run one physics step (call each frame)
     pub fn step(&mut self) {
         let center_x = 0.5;
         let center_y = 0.5;
 
-        for pigment in &mut self.pigments {
-            pigment.apply_gravity(center_x, center_y);
+        // Pairwise inverse-square repulsion keeps clusters from stacking
+        let mut shift = vec![(0.0f32, 0.0f32); self.pigments.len()];
+        for i in 0..self.pigments.len() {
+            for j in (i + 1)..self.pigments.len() {
+                let mut dx = self.pigments[i].x - self.pigments[j].x;
+                let mut dy = self.pigments[i].y - self.pigments[j].y;
+                let mut dist = (dx * dx + dy * dy).sqrt();
+
+                // Coincident pigments split along a golden-angle direction
+                if dist < f32::EPSILON {
+                    let angle = (i + j) as f64 * PI * (3.0 - 5f64.sqrt());
+                    dx = angle.cos() as f32;
+                    dy = angle.sin() as f32;
+                    dist = 1.0;
+                }
+
+                let soft = dist.max(MIN_DISTANCE);
+                let force = self.repulsion / (soft * soft);
+                let (fx, fy) = (dx / dist * force, dy / dist * force);
+
+                shift[i].0 += fx;
+                shift[i].1 += fy;
+                shift[j].0 -= fx;
+                shift[j].1 -= fy;
+            }
+        }
+
+        self.energy = 0.0;
+        for (pigment, (sx, sy)) in self.pigments.iter_mut().zip(shift) {
+            let magnitude = (sx * sx + sy * sy).sqrt();
+            let scale = if magnitude > MAX_DISPLACEMENT {
+                MAX_DISPLACEMENT / magnitude
+            } else {
+                1.0
+            };
+
+            let (x0, y0) = (pigment.x, pigment.y);
+            pigment.x += sx * scale;
+            pigment.y += sy * scale;
+            pigment.apply_gravity_with(center_x, center_y, self.gravity_strength);
+
+            let (vx, vy) = (pigment.x - x0, pigment.y - y0);
+            self.energy += 0.5 * (vx * vx + vy * vy);
         }
     }
 
@@ -213,6 +417,23 @@ impl TruthCanvas {
         data
     }
 
+    /// Score the ingested narrative from mass and resonance (0-100)
+    pub fn resonance_score(&self) -> f32 {
+        if self.pigments.is_empty() {
+            return 0.0;
+        }
+
+        let total_mass: f32 = self.pigments.iter().map(|p| p.mass).sum();
+        let avg_resonance: f32 =
+            self.pigments.iter().map(|p| p.resonance).sum::<f32>() / self.pigments.len() as f32;
+
+        // Score based on mass and resonance
+        let mass_score = (total_mass / self.pigments.len() as f32).clamp(-1.0, 5.0);
+        let normalized_mass = (mass_score + 1.0) / 6.0; // 0 to 1
+
+        (normalized_mass * 0.6 + avg_resonance * 0.4) * 100.0
+    }
+
     /// Get statistics about the canvas
     pub fn get_stats(&self) -> String {
         let total = self.pigments.len();
@@ -232,18 +453,106 @@ impl TruthCanvas {
     }
 }
 
+// =============================================================================
+// UNIFIED VALIDATOR
+// =============================================================================
+
+/// Enneper/Poincaré/Möbius validation pipeline for the browser
+#[wasm_bindgen]
+pub struct WasmUnifiedValidator {
+    inner: UnifiedFieldValidator,
+}
+
+#[wasm_bindgen]
+impl WasmUnifiedValidator {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmUnifiedValidator {
+        WasmUnifiedValidator {
+            inner: UnifiedFieldValidator::new(),
+        }
+    }
+
+    /// Validate a narrative
+    /// Format: {"state":"Crystal","valid":true,"curvature":0.01,"tension":0.05}
+    pub fn validate(&mut self, text: &str) -> String {
+        let state = self.inner.validate(text);
+
+        format!(
+            "{{\"state\":\"{}\",\"valid\":{},\"curvature\":{},\"tension\":{}}}",
+            state.name(),
+            state.is_valid(),
+            json_number(state.curvature()),
+            json_number(state.tension())
+        )
+    }
+}
+
+impl Default for WasmUnifiedValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // =============================================================================
 // HELPER FUNCTIONS
 // =============================================================================
 
-/// Simple hash function for words
-fn simple_hash(s: &str) -> u64 {
-    let mut hash: u64 = 0;
-    for (i, c) in s.bytes().enumerate() {
-        hash = hash.wrapping_mul(31).wrapping_add(c as u64);
-        hash ^= (i as u64).wrapping_mul(17);
+/// Lowercase and strip surrounding punctuation ("Prime," -> "prime")
+fn normalize_word(word: &str) -> String {
+    word.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase()
+}
+
+/// Light Porter-style stemmer for normalized (lowercase) words
+/// Strips plural, -ed and -ing endings, undoubles a final consonant, maps a
+/// final y to i and drops a final e, so "theorems" -> "theorem",
+/// "proving"/"prove" -> "prov" and "optimizing"/"optimize" -> "optimiz".
+/// Stems are never shorter than three characters.
+fn stem(word: &str) -> String {
+    let mut w = word.to_string();
+    let has_vowel = |s: &str| s.chars().any(|c| "aeiouy".contains(c));
+
+    // Plurals
+    if w.ends_with("sses") || w.ends_with("ies") {
+        w.truncate(w.len() - 2);
+    } else if w.ends_with('s') && !w.ends_with("ss") && w.len() > 3 {
+        w.pop();
+    }
+
+    // Past tense and gerunds, when a vowel remains
+    for suffix in ["ing", "ed"] {
+        if let Some(root) = w.strip_suffix(suffix) {
+            if root.len() >= 3 && has_vowel(root) {
+                w.truncate(root.len());
+                let bytes = w.as_bytes();
+                let n = bytes.len();
+                if n >= 2 && bytes[n - 1] == bytes[n - 2] && !b"aeioulsz".contains(&bytes[n - 1]) {
+                    w.pop();
+                }
+                break;
+            }
+        }
+    }
+
+    // Final y/e
+    if w.len() > 3 {
+        if w.ends_with('y') {
+            w.pop();
+            w.push('i');
+        } else if w.ends_with('e') {
+            w.pop();
+        }
+    }
+
+    w
+}
+
+/// JSON has no NaN or Infinity; emit null instead
+fn json_number(value: f64) -> String {
+    if value.is_finite() {
+        format!("{}", value)
+    } else {
+        "null".to_string()
     }
-    hash
 }
 
 /// Convert mass and resonance to RGB color
@@ -295,23 +604,193 @@ pub fn init() {
 /// Analyze a text and return resonance score (0-100)
 #[wasm_bindgen]
 pub fn analyze_quick(text: &str) -> f32 {
-    let canvas = TruthCanvas::new(800, 600);
-    let mut canvas = canvas;
+    // Scoring never rasterizes, so the canvas needs no size
+    let mut canvas = TruthCanvas::new(0, 0);
     canvas.ingest_narrative(text);
+    canvas.resonance_score()
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_lexicon_whole_word() {
+        assert_eq!(Pigment::from_word("primer", 0, 1).mass, 1.0);
+        assert_eq!(Pigment::from_word("Prime,", 0, 1).mass, 5.0);
+        assert_eq!(Pigment::from_word("(Synergy)", 0, 1).mass, -2.0);
+
+        // Without stemming only exact words match
+        let exact = Lexicon {
+            stemming: false,
+            ..Lexicon::default()
+        };
+        assert_eq!(exact.mass("optimized"), 1.0);
+        assert_eq!(exact.mass("theorems"), 1.0);
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_lexicon_stemming() {
+        let lexicon = Lexicon::default();
+        for word in ["theorems", "Proving", "proved", "proofs", "equations", "primes"] {
+            assert_eq!(lexicon.mass(word), lexicon.mass("theorem"), "{}", word);
+        }
+        for word in ["optimizing", "optimized", "leveraging", "stakeholders", "paradigms"] {
+            assert_eq!(lexicon.mass(word), lexicon.mass("synergy"), "{}", word);
+        }
+        assert_eq!(lexicon.mass("primer"), 1.0);
+        assert_eq!(lexicon.mass("optimal"), 1.0);
+
+        assert_eq!(stem("theorems"), "theorem");
+        assert_eq!(stem("proving"), stem("prove"));
+        assert_eq!(stem("stopping"), "stop");
+        assert_eq!(stem("entropies"), stem("entropy"));
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_custom_lexicon() {
+        // The constructor ships the built-in lists; `empty` starts bare
+        assert_eq!(Lexicon::new().mass("prime"), 5.0);
+        assert_eq!(Lexicon::empty().mass("prime"), 1.0);
+
+        let mut lexicon = Lexicon::empty();
+        lexicon.add_anchor("primer");
+        lexicon.anchor_mass = 3.0;
+
+        assert_eq!(Pigment::from_word_with("primer", 0, 1, &lexicon).mass, 3.0);
+        assert_eq!(Pigment::from_word_with("prime", 0, 1, &lexicon).mass, 1.0);
+
+        // New canvases borrow the shared default instead of rebuilding it
+        let mut canvas = TruthCanvas::new(800, 600);
+        assert!(std::ptr::eq(&*canvas.lexicon, default_lexicon()));
+
+        canvas.set_lexicon(lexicon);
+        canvas.ingest_narrative("Primer coat");
+        assert_eq!(canvas.pigments[0].mass, 3.0);
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_repulsion_separates_stacked_pigments() {
+        let mut canvas = TruthCanvas::new(800, 600);
+        let mut heavy = Pigment::from_word("theorem", 0, 1);
+        heavy.x = 0.3;
+        heavy.y = 0.3;
+        canvas.pigments = vec![heavy, heavy];
+
+        for _ in 0..50 {
+            canvas.step();
+        }
 
-    if canvas.pigments.is_empty() {
-        return 0.0;
+        let (a, b) = (canvas.pigments[0], canvas.pigments[1]);
+        let separation = ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt();
+        assert!(separation > 0.05, "separation {}", separation);
+        for p in &canvas.pigments {
+            assert!((0.05..=0.95).contains(&p.x) && (0.05..=0.95).contains(&p.y));
+        }
     }
 
-    let total_mass: f32 = canvas.pigments.iter().map(|p| p.mass).sum();
-    let avg_resonance: f32 =
-        canvas.pigments.iter().map(|p| p.resonance).sum::<f32>() / canvas.pigments.len() as f32;
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_energy_settles() {
+        let mut canvas = TruthCanvas::new(800, 600);
+        canvas.ingest_narrative("prime theorem proof axiom entropy tensor");
+        assert_eq!(canvas.total_energy(), 0.0);
+        assert_eq!(canvas.gravity_strength(), GRAVITY);
+
+        // Gravity against repulsion: each stretch of steps moves less
+        let mut energies = Vec::new();
+        for _ in 0..400 {
+            canvas.step();
+            energies.push(canvas.total_energy());
+        }
+        let mean = |steps: &[f32]| steps.iter().sum::<f32>() / steps.len() as f32;
+        let means: Vec<f32> = energies.chunks(100).map(mean).collect();
+        assert!(means.windows(2).all(|w| w[1] < w[0]), "{:?}", means);
+        assert!(energies[399] < energies[0] * 1e-4);
+
+        // Nothing moves with both forces off
+        canvas.set_gravity_strength(0.0);
+        canvas.set_repulsion(0.0);
+        canvas.step();
+        assert_eq!(canvas.total_energy(), 0.0);
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_seeded_layout() {
+        let text = "The prime theorem leverages a holistic proof";
+        let render = |seed, width, height| {
+            let mut canvas = TruthCanvas::new_seeded(width, height, seed);
+            canvas.ingest_narrative(text);
+            for _ in 0..5 {
+                canvas.step();
+            }
+            canvas.get_render_data()
+        };
+
+        let a = render(42, 800, 600);
+        let b = render(42, 320, 240);
+        let to_bits = |data: &[f32]| data.iter().map(|v| v.to_bits()).collect::<Vec<_>>();
+        assert_eq!(to_bits(&a), to_bits(&b));
+
+        assert_ne!(to_bits(&a), to_bits(&render(7, 800, 600)));
+
+        // Seed 0 is the unseeded layout
+        let unseeded = Pigment::from_word("prime", 1, 3);
+        let zero = Pigment::from_word_seeded("prime", 1, 3, 0, &Lexicon::default());
+        assert_eq!((unseeded.x, unseeded.y), (zero.x, zero.y));
+    }
 
-    // Score based on mass and resonance
-    let mass_score = (total_mass / canvas.pigments.len() as f32)
-        .max(-1.0)
-        .min(5.0);
-    let normalized_mass = (mass_score + 1.0) / 6.0; // 0 to 1
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_shared_word_hash() {
+        // Same reference value geometry_core and physics_core pin
+        assert_eq!(word_hash("truth", 0), 0x690e781);
+        assert_eq!(word_hash("truth", 0), geometry_core::word_hash("truth", 0));
 
-    (normalized_mass * 0.6 + avg_resonance * 0.4) * 100.0
+        let phase = (word_hash("truth", 0) as f64 * PHI) % (2.0 * PI);
+        let resonance = ((phase.sin() + 1.0) / 2.0) as f32;
+        assert_eq!(Pigment::from_word("truth", 0, 1).resonance, resonance);
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_analyze_quick_pinned() {
+        let score = analyze_quick("The prime number theorem follows from the zeta function");
+        assert_eq!(score.to_bits(), 1113824245); // 56.9023
+
+        let mut canvas = TruthCanvas::new(800, 600);
+        canvas.ingest_narrative("The prime number theorem follows from the zeta function");
+        assert_eq!(canvas.resonance_score(), score);
+
+        assert_eq!(analyze_quick(""), 0.0);
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_unified_validator_json() {
+        let mut validator = WasmUnifiedValidator::new();
+        let json = validator.validate("The derivative of a constant is zero");
+
+        let verdict: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let state = verdict["state"].as_str().unwrap();
+        assert!(["Crystal", "Annealing", "Hallucination"].contains(&state));
+        assert_eq!(verdict["valid"].as_bool().unwrap(), state == "Crystal");
+        assert!(verdict["curvature"].is_number());
+        assert!(verdict["tension"].is_number());
+
+        // Empty input is a neutral verdict, not a panic
+        let empty: serde_json::Value = serde_json::from_str(&validator.validate("")).unwrap();
+        assert_eq!(empty["state"], "Annealing");
+    }
 }